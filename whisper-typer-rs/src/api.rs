@@ -0,0 +1,182 @@
+//! Local OpenAI-compatible transcription HTTP endpoint.
+//!
+//! Exposes `POST /v1/audio/transcriptions` against the already-loaded
+//! `WhisperTranscriber` so other tools (editors, scripts) can reuse the
+//! running model instead of spawning a second Whisper instance — the same
+//! drop-in-endpoint idea as edgen. Gated behind `config.api.enabled`.
+
+use std::sync::{Arc, RwLock};
+
+use axum::extract::{Multipart, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::service::VoiceGate;
+use crate::transcriber::WhisperTranscriber;
+
+#[derive(Clone)]
+pub struct TranscriptionApiState {
+    pub transcriber: WhisperTranscriber,
+    /// Whisper initial prompt from `.whisper/vocabulary.txt`, shared with the
+    /// hotkey dictation path so API requests honor the same vocabulary.
+    pub vocabulary: Arc<RwLock<String>>,
+    pub voice_gate: VoiceGate,
+}
+
+#[derive(Serialize)]
+struct TranscriptionResponse {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+fn router(state: TranscriptionApiState) -> Router {
+    Router::new()
+        .route("/v1/audio/transcriptions", post(handle_transcribe))
+        .with_state(state)
+}
+
+/// Start the transcription API server as a background tokio task.
+pub async fn start_api_server(state: TranscriptionApiState, port: u16) {
+    let app = router(state);
+    let addr = format!("127.0.0.1:{port}");
+    let listener = match tokio::net::TcpListener::bind(&addr).await {
+        Ok(l) => l,
+        Err(e) => {
+            warn!("Failed to bind transcription API on {addr}: {e}");
+            return;
+        }
+    };
+    info!("Transcription API server listening on {addr}");
+
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            warn!("Transcription API server error: {e}");
+        }
+    });
+}
+
+/// `POST /v1/audio/transcriptions` (OpenAI-compatible): multipart form with a
+/// `file` field (WAV, 16-bit PCM, mono) and an optional `prompt` field
+/// appended to the loaded vocabulary prompt. Waits for the voice gate to go
+/// idle first so an API request never runs audio through Whisper at the same
+/// time as an in-progress hotkey recording.
+async fn handle_transcribe(
+    State(state): State<TranscriptionApiState>,
+    mut multipart: Multipart,
+) -> Result<Json<TranscriptionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let mut wav_bytes: Option<Vec<u8>> = None;
+    let mut prompt: Option<String> = None;
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or_default() {
+            "file" => {
+                let bytes = field
+                    .bytes()
+                    .await
+                    .map_err(|e| bad_request(format!("Failed to read uploaded file: {e}")))?;
+                wav_bytes = Some(bytes.to_vec());
+            }
+            "prompt" => {
+                prompt = field.text().await.ok();
+            }
+            _ => {}
+        }
+    }
+
+    let wav_bytes = wav_bytes.ok_or_else(|| bad_request("Missing 'file' field".to_string()))?;
+    let samples = decode_wav_pcm16_mono(&wav_bytes).map_err(bad_request)?;
+
+    // Queue behind the voice gate: don't start decoding while a hotkey
+    // recording/processing pass is in flight.
+    while !state.voice_gate.is_idle.load(std::sync::atomic::Ordering::Relaxed) {
+        state.voice_gate.idle_notify.notified().await;
+    }
+
+    let vocab = state.vocabulary.read().unwrap().clone();
+    let extra_prompt = match (vocab.is_empty(), prompt) {
+        (true, p) => p,
+        (false, Some(p)) if !p.is_empty() => Some(format!("{vocab}, {p}")),
+        (false, _) => Some(vocab),
+    };
+
+    let transcriber = state.transcriber.clone();
+    let result = tokio::task::spawn_blocking(move || transcriber.transcribe(&samples, extra_prompt.as_deref()))
+        .await
+        .map_err(|e| internal_error(format!("Transcription task panicked: {e}")))?
+        .map_err(internal_error)?;
+
+    info!("API transcription ({:.0}ms): \"{}\"", result.latency_ms, result.text);
+    Ok(Json(TranscriptionResponse { text: result.text }))
+}
+
+fn bad_request(message: String) -> (StatusCode, Json<ErrorResponse>) {
+    (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: message }))
+}
+
+fn internal_error(message: String) -> (StatusCode, Json<ErrorResponse>) {
+    (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorResponse { error: message }))
+}
+
+/// Minimal dependency-free WAV reader: validates a canonical `RIFF/WAVE`
+/// header, requires 16-bit PCM mono at the service's expected 16kHz sample
+/// rate (matching what `AudioRecorder`/`WhisperTranscriber` already assume),
+/// and returns the samples as normalized `f32`.
+fn decode_wav_pcm16_mono(bytes: &[u8]) -> Result<Vec<f32>, String> {
+    if bytes.len() < 44 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("Uploaded file is not a RIFF/WAVE file".to_string());
+    }
+
+    let mut pos = 12;
+    let mut fmt: Option<(u16, u16, u32, u16)> = None; // (audio_format, channels, sample_rate, bits_per_sample)
+    let mut data: Option<&[u8]> = None;
+
+    while pos + 8 <= bytes.len() {
+        let chunk_id = &bytes[pos..pos + 4];
+        let chunk_size = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        let chunk_start = pos + 8;
+        let chunk_end = (chunk_start + chunk_size).min(bytes.len());
+
+        if chunk_id == b"fmt " && chunk_end - chunk_start >= 16 {
+            let chunk = &bytes[chunk_start..chunk_end];
+            fmt = Some((
+                u16::from_le_bytes(chunk[0..2].try_into().unwrap()),
+                u16::from_le_bytes(chunk[2..4].try_into().unwrap()),
+                u32::from_le_bytes(chunk[4..8].try_into().unwrap()),
+                u16::from_le_bytes(chunk[14..16].try_into().unwrap()),
+            ));
+        } else if chunk_id == b"data" {
+            data = Some(&bytes[chunk_start..chunk_end]);
+        }
+
+        // Chunks are word-aligned; skip the pad byte on odd sizes.
+        pos = chunk_start + chunk_size + (chunk_size % 2);
+    }
+
+    let (audio_format, channels, sample_rate, bits_per_sample) =
+        fmt.ok_or_else(|| "WAV file has no 'fmt ' chunk".to_string())?;
+    let data = data.ok_or_else(|| "WAV file has no 'data' chunk".to_string())?;
+
+    if audio_format != 1 || bits_per_sample != 16 {
+        return Err("Only 16-bit PCM WAV is supported".to_string());
+    }
+    if channels != 1 {
+        return Err("Only mono WAV is supported".to_string());
+    }
+    if sample_rate != 16000 {
+        return Err(format!(
+            "Expected 16kHz audio, got {sample_rate}Hz — resample before upload"
+        ));
+    }
+
+    Ok(data
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32)
+        .collect())
+}
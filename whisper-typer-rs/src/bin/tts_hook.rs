@@ -6,11 +6,14 @@
 //! sessions get short announcements when the speaker is idle.
 //! Logs all events to ~/.tts-hook-history/YYYY-MM-DD.jsonl.
 
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
+use chrono::NaiveDateTime;
+use clap::{Parser, Subcommand};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
@@ -18,16 +21,101 @@ const TTS_API: &str = "http://127.0.0.1:8767";
 
 // --- Event JSON from Claude Code ---
 
+/// A hook event we recognize, with only the fields that variant's handler
+/// actually needs. Parsed via `#[serde(tag = "hook_event_name")]` so each
+/// variant name must match Claude Code's `hook_event_name` value exactly.
 #[derive(Deserialize)]
-#[allow(clippy::struct_field_names)]
-struct HookEvent {
-    hook_event_name: Option<String>,
-    session_id: Option<String>,
-    cwd: Option<String>,
-    source: Option<String>,
-    transcript_path: Option<String>,
-    tool_name: Option<String>,
-    notification_type: Option<String>,
+#[serde(tag = "hook_event_name")]
+enum CheckedEvent {
+    SessionStart {
+        session_id: Option<String>,
+        cwd: Option<String>,
+        source: Option<String>,
+    },
+    Stop {
+        session_id: Option<String>,
+        cwd: Option<String>,
+        transcript_path: Option<String>,
+    },
+    PermissionRequest {
+        session_id: Option<String>,
+        cwd: Option<String>,
+        tool_name: Option<String>,
+    },
+    Notification {
+        session_id: Option<String>,
+        cwd: Option<String>,
+        notification_type: Option<String>,
+    },
+    UserPromptSubmit {
+        session_id: Option<String>,
+        cwd: Option<String>,
+        transcript_path: Option<String>,
+    },
+}
+
+impl CheckedEvent {
+    fn session_id(&self) -> Option<&str> {
+        match self {
+            Self::SessionStart { session_id, .. }
+            | Self::Stop { session_id, .. }
+            | Self::PermissionRequest { session_id, .. }
+            | Self::Notification { session_id, .. }
+            | Self::UserPromptSubmit { session_id, .. } => session_id.as_deref(),
+        }
+    }
+
+    fn cwd(&self) -> Option<&str> {
+        match self {
+            Self::SessionStart { cwd, .. }
+            | Self::Stop { cwd, .. }
+            | Self::PermissionRequest { cwd, .. }
+            | Self::Notification { cwd, .. }
+            | Self::UserPromptSubmit { cwd, .. } => cwd.as_deref(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::SessionStart { .. } => "SessionStart",
+            Self::Stop { .. } => "Stop",
+            Self::PermissionRequest { .. } => "PermissionRequest",
+            Self::Notification { .. } => "Notification",
+            Self::UserPromptSubmit { .. } => "UserPromptSubmit",
+        }
+    }
+}
+
+/// A hook event, either matched to a known [`CheckedEvent`] variant or, for
+/// any `hook_event_name` we don't recognize yet, kept as the raw JSON so it
+/// still lands in the history file instead of being silently dropped.
+enum ParsedEvent {
+    Checked(CheckedEvent),
+    Dynamic(serde_json::Value),
+}
+
+/// Parse a hook event payload, preferring the type-safe [`CheckedEvent`]
+/// shape and falling back to [`ParsedEvent::Dynamic`] when the event doesn't
+/// match any known variant (new Claude Code hook, unexpected shape, etc).
+/// Returns `None` only when `input` isn't JSON at all.
+fn parse_event(input: &str) -> Option<ParsedEvent> {
+    let value: serde_json::Value = serde_json::from_str(input).ok()?;
+    match serde_json::from_value::<CheckedEvent>(value.clone()) {
+        Ok(checked) => Some(ParsedEvent::Checked(checked)),
+        Err(_) => Some(ParsedEvent::Dynamic(value)),
+    }
+}
+
+/// Serialize `value` and truncate to `max_chars`, for embedding an unknown
+/// event's payload in a [`HistoryRecord`] detail string.
+fn truncate_json(value: &serde_json::Value, max_chars: usize) -> String {
+    let serialized = value.to_string();
+    let truncated: String = serialized.chars().take(max_chars).collect();
+    if truncated.len() < serialized.len() {
+        format!("{truncated}...")
+    } else {
+        truncated
+    }
 }
 
 // --- TTS API request ---
@@ -79,11 +167,12 @@ struct FocusState {
     session_id: String,
     project: String,
     timestamp: String,
+    transcript_path: String,
 }
 
 // --- History record ---
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct HistoryRecord {
     timestamp: String,
     event: String,
@@ -111,6 +200,98 @@ fn history_dir() -> PathBuf {
         .join(".tts-hook-history")
 }
 
+// --- Remote fallback notification ---
+
+/// Config for [`notify_remote`], read from `remote_notify.toml` next to
+/// `history_dir()`. Either or both channels may be configured; whichever
+/// has non-empty fields gets used.
+#[derive(Deserialize, Default)]
+struct RemoteNotifyConfig {
+    #[serde(default)]
+    webhook_url: Option<String>,
+    #[serde(default)]
+    telegram_bot_token: Option<String>,
+    #[serde(default)]
+    telegram_chat_id: Option<String>,
+}
+
+fn remote_notify_config_path() -> PathBuf {
+    history_dir().join("remote_notify.toml")
+}
+
+fn load_remote_notify_config() -> RemoteNotifyConfig {
+    let Ok(contents) = fs::read_to_string(remote_notify_config_path()) else {
+        return RemoteNotifyConfig::default();
+    };
+    toml::from_str(&contents).unwrap_or_default()
+}
+
+/// Fire a remote notification (generic webhook and/or Telegram bot message)
+/// for a high-priority event when the local TTS API is unreachable, so
+/// stepping away from the machine doesn't mean missing a permission prompt
+/// or a finished turn. Returns true if at least one channel was configured
+/// and accepted the request.
+async fn notify_remote(client: &Client, text: &str) -> bool {
+    let config = load_remote_notify_config();
+    let mut sent = false;
+
+    if let Some(url) = config.webhook_url.as_deref().filter(|u| !u.is_empty()) {
+        sent |= client
+            .post(url)
+            .json(&serde_json::json!({ "text": text }))
+            .send()
+            .await
+            .is_ok();
+    }
+
+    if let (Some(token), Some(chat_id)) = (
+        config.telegram_bot_token.as_deref().filter(|t| !t.is_empty()),
+        config.telegram_chat_id.as_deref().filter(|c| !c.is_empty()),
+    ) {
+        let url = format!("https://api.telegram.org/bot{token}/sendMessage");
+        sent |= client
+            .post(&url)
+            .json(&serde_json::json!({ "chat_id": chat_id, "text": text }))
+            .send()
+            .await
+            .is_ok();
+    }
+
+    sent
+}
+
+/// Build the text a high-priority event would have spoken, for use as the
+/// remote-notification payload when the TTS API is down. Reuses the same
+/// strings the normal handlers build. Returns `None` for events that
+/// wouldn't have spoken anyway (so they stay `"skipped"`, not
+/// `"remote_notified"`) and for anything that didn't parse as a
+/// [`CheckedEvent`].
+fn build_fallback_text(event: &ParsedEvent, session_id: &str, project: &str, is_focus: bool) -> Option<String> {
+    let ParsedEvent::Checked(checked) = event else {
+        return None;
+    };
+
+    match checked {
+        CheckedEvent::PermissionRequest { tool_name, .. } => {
+            let tool = tool_name.as_deref().unwrap_or("unknown tool");
+            Some(format!("{project} needs permission for {tool}."))
+        }
+        CheckedEvent::Notification { notification_type, .. }
+            if is_focus && notification_type.as_deref() == Some("permission_prompt") =>
+        {
+            Some("Permission needed.".to_string())
+        }
+        CheckedEvent::Stop { transcript_path, .. } if is_focus => {
+            let text = extract_last_assistant_text(transcript_path.as_deref()?)?;
+            if is_duplicate_stop(session_id, &text) {
+                return None;
+            }
+            Some(text)
+        }
+        _ => None,
+    }
+}
+
 // --- Focus session tracking ---
 
 fn focus_file() -> PathBuf {
@@ -136,11 +317,12 @@ fn read_focus() -> Option<FocusState> {
 }
 
 /// Write focus session.
-fn write_focus(session_id: &str, project: &str) {
+fn write_focus(session_id: &str, project: &str, transcript_path: &str) {
     let state = FocusState {
         session_id: session_id.to_string(),
         project: project.to_string(),
         timestamp: now_timestamp(),
+        transcript_path: transcript_path.to_string(),
     };
     let _ = fs::create_dir_all(history_dir());
     if let Ok(json) = serde_json::to_string(&state) {
@@ -157,6 +339,63 @@ fn is_focus_session(session_id: &str) -> bool {
     }
 }
 
+/// How long a focus session's transcript file can go untouched before
+/// [`release_stale_focus`] treats the session as dead. Configurable via
+/// `TTS_HOOK_FOCUS_STALE_SECS`, defaulting to 2 minutes.
+fn focus_liveness_timeout() -> Duration {
+    std::env::var("TTS_HOOK_FOCUS_STALE_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(120))
+}
+
+/// A focus session is stale if its transcript file is missing, or hasn't
+/// been written to within [`focus_liveness_timeout`] — the transcript only
+/// stops growing once the Claude Code process that owns it is gone.
+fn focus_transcript_is_stale(focus: &FocusState) -> bool {
+    let Ok(meta) = fs::metadata(&focus.transcript_path) else {
+        return true;
+    };
+    let Ok(modified) = meta.modified() else {
+        return false;
+    };
+    modified.elapsed().unwrap_or_default() > focus_liveness_timeout()
+}
+
+/// Liveness check for the current focus session, run on every hook
+/// invocation since the process is too short-lived for a background
+/// watchdog. If the focus session's transcript has gone stale, treat it as
+/// dead: drop `focus_file()`, cancel any pending reminder, and log a
+/// `focus_released` record — so a `UserPromptSubmit` elsewhere can claim
+/// focus immediately instead of waiting out [`read_focus`]'s 6-hour expiry.
+async fn release_stale_focus(client: &Client) {
+    let Some(focus) = read_focus() else {
+        return;
+    };
+    if !focus_transcript_is_stale(&focus) {
+        return;
+    }
+
+    let _ = fs::remove_file(focus_file());
+    let cancel_sent = client.post(format!("{TTS_API}/cancel-reminder")).send().await.is_ok();
+
+    save_record(&HistoryRecord {
+        timestamp: now_timestamp(),
+        event: "FocusWatchdog".to_string(),
+        action: "focus_released".to_string(),
+        detail: Some(format!("stale focus session for {}", focus.project)),
+        text: None,
+        text_chars: None,
+        duration_ms: 0,
+        tts_api_up: cancel_sent,
+        session_id: Some(focus.session_id),
+        cwd: None,
+        project: Some(focus.project),
+        is_focus: false,
+    });
+}
+
 // --- Per-session dedup ---
 
 /// Dedup file for a specific session (first 8 chars of UUID).
@@ -201,16 +440,26 @@ fn cleanup_stale_files() {
             }
         }
     }
+
+    // Remove daemon command files that never got picked up (no daemon running)
+    if let Ok(entries) = fs::read_dir(daemon_commands_dir()) {
+        for entry in entries.flatten() {
+            if let Ok(meta) = entry.metadata() {
+                if let Ok(modified) = meta.modified() {
+                    if modified.elapsed().unwrap_or_default() > Duration::from_secs(3600) {
+                        let _ = fs::remove_file(entry.path());
+                    }
+                }
+            }
+        }
+    }
 }
 
 // --- Helpers ---
 
 /// Extract project name from cwd (last path component).
-fn project_name(event: &HookEvent) -> String {
-    event
-        .cwd
-        .as_deref()
-        .and_then(|p| std::path::Path::new(p).file_name())
+fn project_name(cwd: Option<&str>) -> String {
+    cwd.and_then(|p| std::path::Path::new(p).file_name())
         .and_then(|n| n.to_str())
         .unwrap_or("unknown")
         .to_string()
@@ -251,10 +500,62 @@ async fn is_tts_idle(client: &Client) -> bool {
     !status.speaking && !status.reminder_active
 }
 
+// --- CLI ---
+
+/// `tts-hook` is invoked two ways: with no arguments, reading a Claude Code
+/// hook event from stdin (the default), or with a subcommand like `report`
+/// for ad-hoc queries over the history it has logged.
+#[derive(Parser)]
+#[command(name = "tts-hook")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Cmd>,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Turn the JSONL event history into per-project session timesheets.
+    Report {
+        /// Only include days on or after this date (YYYY-MM-DD).
+        #[arg(long)]
+        since: Option<String>,
+        /// Only include this project.
+        #[arg(long)]
+        project: Option<String>,
+        /// Idle gap, in seconds, that closes an active span.
+        #[arg(long, default_value_t = 300)]
+        idle_gap_secs: u64,
+        /// Print the timesheet as JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Run as a persistent daemon owning cross-session reminder escalation,
+    /// so "Claude is waiting" reminders survive across one turn's many
+    /// independent hook invocations instead of resetting each time.
+    Daemon {
+        /// How often to poll for commands and check escalation, in seconds.
+        #[arg(long, default_value_t = 5)]
+        poll_interval_secs: u64,
+    },
+}
+
 // --- Main ---
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Cmd::Report { since, project, idle_gap_secs, json }) => {
+            run_report(since.as_deref(), project.as_deref(), idle_gap_secs, json);
+            return;
+        }
+        Some(Cmd::Daemon { poll_interval_secs }) => {
+            run_daemon(poll_interval_secs).await;
+            return;
+        }
+        None => {}
+    }
+
     let t0 = Instant::now();
 
     // Clean up stale dedup files
@@ -267,20 +568,38 @@ async fn main() {
     }
 
     // Parse event
-    let event: HookEvent = match serde_json::from_str(&input) {
-        Ok(e) => e,
-        Err(_) => return,
+    let Some(event) = parse_event(&input) else {
+        return;
     };
 
-    let event_name = match &event.hook_event_name {
-        Some(name) => name.clone(),
-        None => return,
+    let event_name = match &event {
+        ParsedEvent::Checked(checked) => checked.name().to_string(),
+        ParsedEvent::Dynamic(value) => value
+            .get("hook_event_name")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("unknown")
+            .to_string(),
     };
 
     // Extract session context
-    let session_id = event.session_id.clone().unwrap_or_default();
-    let project = project_name(&event);
-    let is_focus = is_focus_session(&session_id);
+    let (session_id, cwd) = match &event {
+        ParsedEvent::Checked(checked) => (
+            checked.session_id().unwrap_or_default().to_string(),
+            checked.cwd().map(str::to_string),
+        ),
+        ParsedEvent::Dynamic(value) => (
+            value
+                .get("session_id")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            value
+                .get("cwd")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+        ),
+    };
+    let project = project_name(cwd.as_deref());
 
     // Build HTTP client with short timeouts
     let client = Client::builder()
@@ -289,6 +608,11 @@ async fn main() {
         .build()
         .unwrap_or_else(|_| Client::new());
 
+    // Release focus if its owning session died without cleaning up, before
+    // deciding whether this event is in the focus session.
+    release_stale_focus(&client).await;
+    let is_focus = is_focus_session(&session_id);
+
     // Quick connectivity check — exit cleanly if TTS API is down
     let tts_api_up = client
         .get(format!("{TTS_API}/status"))
@@ -297,32 +621,57 @@ async fn main() {
         .is_ok();
 
     if !tts_api_up {
+        let fallback_text = build_fallback_text(&event, &session_id, &project, is_focus);
+        let (action, detail) = match &fallback_text {
+            Some(text) if notify_remote(&client, text).await => {
+                ("remote_notified".to_string(), None)
+            }
+            Some(_) => (
+                "skipped".to_string(),
+                Some("TTS API unreachable; no remote_notify.toml channel configured or reachable".into()),
+            ),
+            None => ("skipped".to_string(), Some("TTS API unreachable".into())),
+        };
+
+        let text_chars = fallback_text.as_ref().map(String::len);
         save_record(&HistoryRecord {
             timestamp: now_timestamp(),
             event: event_name,
-            action: "skipped".into(),
-            detail: Some("TTS API unreachable".into()),
-            text: None,
-            text_chars: None,
+            action,
+            detail,
+            text: fallback_text,
+            text_chars,
             duration_ms: u64::try_from(t0.elapsed().as_millis()).unwrap_or(u64::MAX),
             tts_api_up: false,
             session_id: Some(session_id),
-            cwd: event.cwd.clone(),
+            cwd,
             project: Some(project),
             is_focus,
         });
         return;
     }
 
-    let (action, detail, text) = match event_name.as_str() {
-        "SessionStart" => handle_session_start(&client, &event, &project, is_focus).await,
-        "Stop" => handle_stop(&client, &event, &session_id, &project, is_focus).await,
-        "PermissionRequest" => handle_permission(&client, &event, &project).await,
-        "Notification" => handle_notification(&client, &event, &project, is_focus).await,
-        "UserPromptSubmit" => {
-            handle_user_prompt_submit(&client, &session_id, &project).await
+    let (action, detail, text) = match &event {
+        ParsedEvent::Checked(CheckedEvent::SessionStart { source, .. }) => {
+            handle_session_start(&client, source.as_deref(), &project, is_focus).await
+        }
+        ParsedEvent::Checked(CheckedEvent::Stop { transcript_path, .. }) => {
+            handle_stop(&client, transcript_path.as_deref(), &session_id, &project, is_focus).await
         }
-        _ => ("ignored".into(), Some("unknown event".into()), None),
+        ParsedEvent::Checked(CheckedEvent::PermissionRequest { tool_name, .. }) => {
+            handle_permission(&client, tool_name.as_deref(), &session_id, &project).await
+        }
+        ParsedEvent::Checked(CheckedEvent::Notification { notification_type, .. }) => {
+            handle_notification(&client, notification_type.as_deref(), &project, is_focus).await
+        }
+        ParsedEvent::Checked(CheckedEvent::UserPromptSubmit { transcript_path, .. }) => {
+            handle_user_prompt_submit(&client, &session_id, &project, transcript_path.as_deref()).await
+        }
+        ParsedEvent::Dynamic(value) => (
+            "ignored".to_string(),
+            Some(format!("unknown event; payload={}", truncate_json(value, 500))),
+            None,
+        ),
     };
 
     let text_chars = text.as_ref().map(String::len);
@@ -336,7 +685,7 @@ async fn main() {
         duration_ms: u64::try_from(t0.elapsed().as_millis()).unwrap_or(u64::MAX),
         tts_api_up: true,
         session_id: Some(session_id),
-        cwd: event.cwd.clone(),
+        cwd,
         project: Some(project),
         is_focus,
     });
@@ -346,12 +695,12 @@ async fn main() {
 
 async fn handle_session_start(
     client: &Client,
-    event: &HookEvent,
+    source: Option<&str>,
     project: &str,
     is_focus: bool,
 ) -> (String, Option<String>, Option<String>) {
     // Skip resume and compaction restarts
-    if let Some(source) = &event.source {
+    if let Some(source) = source {
         if source == "resume" || source == "compact" {
             return (
                 "skipped".into(),
@@ -403,12 +752,12 @@ async fn handle_session_start(
 
 async fn handle_stop(
     client: &Client,
-    event: &HookEvent,
+    transcript_path: Option<&str>,
     session_id: &str,
     project: &str,
     is_focus: bool,
 ) -> (String, Option<String>, Option<String>) {
-    let transcript_path = match &event.transcript_path {
+    let transcript_path = match transcript_path {
         Some(p) if !p.is_empty() => p,
         _ => return ("skipped".into(), Some("no transcript path".into()), None),
     };
@@ -442,6 +791,10 @@ async fn handle_stop(
             })
             .send()
             .await;
+        send_daemon_command(&DaemonCommand::StartReminder {
+            session_id: session_id.to_string(),
+            project: project.to_string(),
+        });
 
         ("spoke".into(), Some(format!("focus ({project})")), Some(text))
     } else {
@@ -476,10 +829,11 @@ async fn handle_stop(
 
 async fn handle_permission(
     client: &Client,
-    event: &HookEvent,
+    tool_name: Option<&str>,
+    session_id: &str,
     project: &str,
 ) -> (String, Option<String>, Option<String>) {
-    let tool = event.tool_name.as_deref().unwrap_or("unknown tool");
+    let tool = tool_name.unwrap_or("unknown tool");
 
     // Always speak permission requests with project context
     let text = format!("{project} needs permission for {tool}.");
@@ -494,13 +848,17 @@ async fn handle_permission(
         })
         .send()
         .await;
+    send_daemon_command(&DaemonCommand::StartReminder {
+        session_id: session_id.to_string(),
+        project: project.to_string(),
+    });
 
     ("spoke".into(), Some(format!("{project}/{tool}")), Some(text))
 }
 
 async fn handle_notification(
     client: &Client,
-    event: &HookEvent,
+    notification_type: Option<&str>,
     project: &str,
     is_focus: bool,
 ) -> (String, Option<String>, Option<String>) {
@@ -513,7 +871,7 @@ async fn handle_notification(
         );
     }
 
-    let (text, event_type) = match event.notification_type.as_deref() {
+    let (text, event_type) = match notification_type {
         Some("idle_prompt") => {
             // Skip — the reminder system already handles post-stop reminders,
             // and Claude Code's idle_prompt fires false positives.
@@ -543,7 +901,7 @@ async fn handle_notification(
 
     (
         "spoke".into(),
-        event.notification_type.clone(),
+        notification_type.map(str::to_string),
         Some(text.into()),
     )
 }
@@ -552,16 +910,18 @@ async fn handle_user_prompt_submit(
     client: &Client,
     session_id: &str,
     project: &str,
+    transcript_path: Option<&str>,
 ) -> (String, Option<String>, Option<String>) {
     // Claim focus for this session
     if !session_id.is_empty() {
-        write_focus(session_id, project);
+        write_focus(session_id, project, transcript_path.unwrap_or_default());
     }
 
     let _ = client
         .post(format!("{TTS_API}/cancel-reminder"))
         .send()
         .await;
+    send_daemon_command(&DaemonCommand::CancelReminder { session_id: session_id.to_string() });
 
     ("cancel_reminder".into(), Some(format!("focus={project}")), None)
 }
@@ -600,3 +960,319 @@ fn extract_last_assistant_text(path: &str) -> Option<String> {
 
     None
 }
+
+// --- `report` subcommand ---
+
+/// Per-(date, project) aggregate produced by [`run_report`].
+#[derive(Default, Serialize)]
+struct TimesheetRow {
+    date: String,
+    project: String,
+    active_seconds: u64,
+    turns: usize,
+    stops: usize,
+}
+
+fn parse_record_timestamp(timestamp: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.3f").ok()
+}
+
+/// Merge a session's (prompt-submit, stop) turn intervals into contiguous
+/// active spans — a turn starting within `idle_gap_secs` of the previous
+/// turn's end extends the current span instead of opening a new one — and
+/// return the total covered duration. Mirrors how a git-timesheet tool
+/// folds commit timestamps into work sessions.
+fn merge_active_seconds(turns: &mut [(NaiveDateTime, NaiveDateTime)], idle_gap_secs: u64) -> u64 {
+    if turns.is_empty() {
+        return 0;
+    }
+    turns.sort_by_key(|(start, _)| *start);
+
+    let idle_gap = chrono::Duration::seconds(i64::try_from(idle_gap_secs).unwrap_or(i64::MAX));
+    let mut total = chrono::Duration::zero();
+    let (mut span_start, mut span_end) = turns[0];
+
+    for &(start, end) in &turns[1..] {
+        if start - span_end <= idle_gap {
+            span_end = span_end.max(end);
+        } else {
+            total += span_end - span_start;
+            span_start = start;
+            span_end = end;
+        }
+    }
+    total += span_end - span_start;
+
+    u64::try_from(total.num_seconds()).unwrap_or(0)
+}
+
+/// Derive per-(date, project) session timesheets from the JSONL history: for
+/// each session, pair each `UserPromptSubmit` with the next `Stop` into a
+/// turn, merge turns into active spans with [`merge_active_seconds`], and
+/// sum active time, turn count, and `Stop` count per project per day.
+fn run_report(since: Option<&str>, project_filter: Option<&str>, idle_gap_secs: u64, as_json: bool) {
+    let dir = history_dir();
+
+    let mut dates: Vec<String> = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .filter_map(std::result::Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.strip_suffix(".jsonl").map(str::to_string)
+        })
+        .filter(|date| date.len() == 10)
+        .collect();
+    dates.sort();
+    if let Some(since) = since {
+        dates.retain(|date| date.as_str() >= since);
+    }
+
+    let mut agg: BTreeMap<(String, String), TimesheetRow> = BTreeMap::new();
+
+    for date in &dates {
+        let Ok(contents) = fs::read_to_string(dir.join(format!("{date}.jsonl"))) else {
+            continue;
+        };
+        let records: Vec<HistoryRecord> =
+            contents.lines().filter_map(|line| serde_json::from_str(line).ok()).collect();
+
+        let mut by_session: HashMap<&str, Vec<&HistoryRecord>> = HashMap::new();
+        for record in &records {
+            if let Some(session_id) = &record.session_id {
+                by_session.entry(session_id.as_str()).or_default().push(record);
+            }
+        }
+
+        for mut events in by_session.into_values() {
+            events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+            let project = events
+                .iter()
+                .find_map(|e| e.project.clone())
+                .unwrap_or_else(|| "unknown".to_string());
+            if project_filter.is_some_and(|filter| filter != project) {
+                continue;
+            }
+
+            let mut turns = Vec::new();
+            let mut pending_prompt = None;
+            let mut stops = 0usize;
+
+            for event in &events {
+                let Some(ts) = parse_record_timestamp(&event.timestamp) else {
+                    continue;
+                };
+                match event.event.as_str() {
+                    "UserPromptSubmit" => {
+                        pending_prompt.get_or_insert(ts);
+                    }
+                    "Stop" => {
+                        stops += 1;
+                        if let Some(start) = pending_prompt.take() {
+                            turns.push((start, ts));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let turn_count = turns.len();
+            let active_seconds = merge_active_seconds(&mut turns, idle_gap_secs);
+
+            let row = agg.entry((date.clone(), project.clone())).or_insert_with(|| TimesheetRow {
+                date: date.clone(),
+                project,
+                ..TimesheetRow::default()
+            });
+            row.active_seconds += active_seconds;
+            row.turns += turn_count;
+            row.stops += stops;
+        }
+    }
+
+    if as_json {
+        let rows: Vec<&TimesheetRow> = agg.values().collect();
+        println!("{}", serde_json::to_string_pretty(&rows).unwrap_or_else(|_| "[]".to_string()));
+        return;
+    }
+
+    if agg.is_empty() {
+        println!("No history records found.");
+        return;
+    }
+
+    println!("{:<12} {:<24} {:>10} {:>6} {:>6}", "DATE", "PROJECT", "ACTIVE", "TURNS", "STOPS");
+    for row in agg.values() {
+        println!(
+            "{:<12} {:<24} {:>10} {:>6} {:>6}",
+            row.date,
+            row.project,
+            format_hms(row.active_seconds),
+            row.turns,
+            row.stops
+        );
+    }
+}
+
+/// Render a second count as `HhMMm` or `MmSSs`, for the report table.
+fn format_hms(seconds: u64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+    if hours > 0 {
+        format!("{hours}h{minutes:02}m")
+    } else {
+        format!("{minutes}m{secs:02}s")
+    }
+}
+
+// --- `daemon` subcommand ---
+
+/// Commands a short-lived hook invocation drops for the daemon to pick up,
+/// since the two processes share no memory. Delivered as one file per
+/// command under `daemon_commands_dir()` — the daemon deletes each file as
+/// it's consumed, so a command is never double-applied.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "cmd")]
+enum DaemonCommand {
+    StartReminder { session_id: String, project: String },
+    CancelReminder { session_id: String },
+}
+
+fn daemon_commands_dir() -> PathBuf {
+    history_dir().join("daemon-commands")
+}
+
+/// Drop a command file for the daemon to pick up on its next poll. Best
+/// effort: if no daemon is running, the file is harmless and gets swept up
+/// by [`cleanup_stale_files`] like the other per-session state files.
+fn send_daemon_command(cmd: &DaemonCommand) {
+    let dir = daemon_commands_dir();
+    let _ = fs::create_dir_all(&dir);
+    let Ok(json) = serde_json::to_string(cmd) else {
+        return;
+    };
+    let name = format!("{}-{}.json", now_timestamp(), std::process::id());
+    let _ = fs::write(dir.join(name), json);
+}
+
+/// Escalating reminder state the daemon owns for one focus session.
+struct ReminderState {
+    project: String,
+    reminder_start: Instant,
+    /// Number of [`ESCALATION_SCHEDULE`] steps already spoken for this
+    /// session — distinct from how many steps time has merely reached, so a
+    /// step never speaks twice even if the speaker is busy for several
+    /// polls in a row. Steps reached while the speaker was busy are skipped:
+    /// only the latest reached step is spoken once it goes idle.
+    announced: usize,
+}
+
+/// Seconds since `reminder_start`, and the announcement text, at each
+/// escalation step — re-announcing a waiting project more insistently the
+/// longer it goes unattended.
+const ESCALATION_SCHEDULE: &[(u64, &str)] = &[
+    (60, "Still waiting on {project}."),
+    (180, "{project} has been waiting a while."),
+    (480, "{project} really needs your attention now."),
+];
+
+/// `tts-hook daemon`: a single long-running tokio loop that owns
+/// cross-session reminder escalation, since a one-shot hook invocation has
+/// no memory of how long a project has been waiting. Polls
+/// `daemon_commands_dir()` for `start_reminder`/`cancel_reminder` commands
+/// and, for every session with an active reminder, re-announces it at each
+/// [`ESCALATION_SCHEDULE`] step once [`is_tts_idle`] so it never talks over
+/// active speech.
+async fn run_daemon(poll_interval_secs: u64) {
+    let client = Client::builder()
+        .connect_timeout(Duration::from_millis(300))
+        .timeout(Duration::from_secs(3))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+
+    let mut reminders: HashMap<String, ReminderState> = HashMap::new();
+
+    loop {
+        poll_daemon_commands(&mut reminders);
+        tick_reminders(&client, &mut reminders).await;
+        tokio::time::sleep(Duration::from_secs(poll_interval_secs)).await;
+    }
+}
+
+fn poll_daemon_commands(reminders: &mut HashMap<String, ReminderState>) {
+    let Ok(entries) = fs::read_dir(daemon_commands_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let _ = fs::remove_file(&path);
+
+        match serde_json::from_str::<DaemonCommand>(&contents) {
+            Ok(DaemonCommand::StartReminder { session_id, project }) => {
+                reminders.insert(
+                    session_id,
+                    ReminderState {
+                        project,
+                        reminder_start: Instant::now(),
+                        announced: 0,
+                    },
+                );
+            }
+            Ok(DaemonCommand::CancelReminder { session_id }) => {
+                reminders.remove(&session_id);
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// Number of [`ESCALATION_SCHEDULE`] steps time has reached for a reminder
+/// that started `elapsed_secs` ago.
+fn steps_reached(elapsed_secs: u64) -> usize {
+    ESCALATION_SCHEDULE.iter().filter(|(secs, _)| elapsed_secs >= *secs).count()
+}
+
+/// Find the session with an unspoken escalation step furthest past due,
+/// speak only that one (so reminders for several waiting sessions take
+/// turns rather than talking over each other), and mark it announced. The
+/// rest catch up, or escalate further, on the next poll.
+async fn tick_reminders(client: &Client, reminders: &mut HashMap<String, ReminderState>) {
+    if reminders.is_empty() {
+        return;
+    }
+
+    let most_urgent = reminders
+        .iter()
+        .filter_map(|(session_id, state)| {
+            let reached = steps_reached(state.reminder_start.elapsed().as_secs());
+            (reached > state.announced).then_some((session_id.clone(), reached))
+        })
+        .max_by_key(|(_, reached)| *reached);
+
+    let Some((session_id, reached)) = most_urgent else {
+        return;
+    };
+    if !is_tts_idle(client).await {
+        return;
+    }
+
+    let state = reminders.get_mut(&session_id).expect("key came from this map");
+    let text = ESCALATION_SCHEDULE[reached - 1].1.replace("{project}", &state.project);
+    state.announced = reached;
+
+    let _ = client
+        .post(format!("{TTS_API}/speak"))
+        .json(&SpeakRequest {
+            text,
+            summarize: false,
+            event_type: "daemon_reminder".into(),
+            start_reminder: false,
+        })
+        .send()
+        .await;
+}
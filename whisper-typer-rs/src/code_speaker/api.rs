@@ -8,8 +8,10 @@
 //! speaking (POST /user-input), non-focus deferred items are re-queued
 //! so they're not silently lost.
 
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use axum::extract::State;
 use axum::routing::{get, post};
@@ -18,6 +20,8 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{info, warn};
 
+use crate::config::TTSConfig;
+
 use super::history::{save_tts_record, TTSRecord};
 use super::reminder::ReminderManager;
 use super::summarizer::OllamaSummarizer;
@@ -25,6 +29,56 @@ use super::tts::KokoroTtsEngine;
 
 const MAX_DEFERRED: usize = 20;
 
+/// Number of recent playback durations the pacer averages over.
+const PACING_WINDOW: usize = 5;
+
+/// Adaptive inter-utterance pacing (the "tranquilizer" pattern): tracks a
+/// rolling window of recent playback durations and sleeps proportionally
+/// between jobs so a burst of queued speech doesn't monopolize the audio
+/// device with a wall of back-to-back utterances.
+pub struct Pacer {
+    recent_ms: Mutex<VecDeque<f64>>,
+    duty_cycle: f32,
+    min_gap_ms: u64,
+    max_gap_ms: u64,
+    current_gap_ms: AtomicU64,
+}
+
+impl Pacer {
+    pub fn new(config: &TTSConfig) -> Self {
+        Self {
+            recent_ms: Mutex::new(VecDeque::with_capacity(PACING_WINDOW)),
+            duty_cycle: config.pacing_duty_cycle.clamp(0.01, 1.0),
+            min_gap_ms: config.pacing_min_gap_ms,
+            max_gap_ms: config.pacing_max_gap_ms,
+            current_gap_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a just-finished playback duration and compute the gap to
+    /// sleep before starting the next job.
+    fn record(&self, playback_ms: f64) -> Duration {
+        let average_ms = {
+            let mut recent = self.recent_ms.lock().unwrap();
+            if recent.len() == PACING_WINDOW {
+                recent.pop_front();
+            }
+            recent.push_back(playback_ms);
+            recent.iter().sum::<f64>() / recent.len() as f64
+        };
+
+        let gap_ms = (average_ms * (1.0 / f64::from(self.duty_cycle) - 1.0))
+            .clamp(self.min_gap_ms as f64, self.max_gap_ms as f64);
+        self.current_gap_ms.store(gap_ms as u64, Ordering::Relaxed);
+        Duration::from_millis(gap_ms as u64)
+    }
+
+    /// Most recently computed gap, for `/status` reporting.
+    pub fn current_gap_ms(&self) -> u64 {
+        self.current_gap_ms.load(Ordering::Relaxed)
+    }
+}
+
 /// Short session ID for logging (first 8 chars or "manual" if empty).
 fn short_sid(sid: &str) -> &str {
     if sid.is_empty() {
@@ -43,6 +97,7 @@ pub struct TtsApiState {
     pub queue_tx: mpsc::Sender<SpeakJob>,
     pub generation: Arc<AtomicU64>,
     pub deferred: Arc<Mutex<Vec<SpeakJob>>>,
+    pub pacer: Arc<Pacer>,
 }
 
 /// A queued speak request with generation stamp for cancellation.
@@ -98,6 +153,7 @@ struct StatusResponse {
     reminder_count: u32,
     queue_depth: usize,
     deferred_count: usize,
+    pacing_gap_ms: u64,
 }
 
 #[derive(Serialize)]
@@ -140,6 +196,7 @@ pub fn router(state: TtsApiState) -> Router {
     Router::new()
         .route("/status", get(handle_status))
         .route("/speak", post(handle_speak))
+        .route("/voices", get(handle_voices))
         .route("/set-voice", post(handle_set_voice))
         .route("/cancel", post(handle_cancel))
         .route("/cancel-reminder", post(handle_cancel_reminder))
@@ -158,6 +215,7 @@ pub async fn start_tts_api(state: TtsApiState, port: u16, queue_rx: mpsc::Receiv
         state.reminder.clone(),
         state.generation.clone(),
         state.deferred.clone(),
+        state.pacer.clone(),
     );
 
     let app = router(state);
@@ -186,6 +244,7 @@ fn spawn_queue_consumer(
     reminder: Arc<ReminderManager>,
     generation: Arc<AtomicU64>,
     deferred: Arc<Mutex<Vec<SpeakJob>>>,
+    pacer: Arc<Pacer>,
 ) {
     tokio::spawn(async move {
         while let Some(job) = rx.recv().await {
@@ -227,7 +286,7 @@ fn spawn_queue_consumer(
             let backup = job.clone();
             let event_type = job.event_type.clone();
 
-            let cancelled = do_speak(
+            let outcome = do_speak(
                 &tts,
                 &summarizer,
                 &reminder,
@@ -237,6 +296,10 @@ fn spawn_queue_consumer(
                 job.start_reminder,
             )
             .await;
+            let cancelled = outcome.cancelled;
+
+            let gap = pacer.record(outcome.playback_ms);
+            tokio::time::sleep(gap).await;
 
             if cancelled {
                 // Only defer first-time cancelled items. Already-retried items are dropped.
@@ -278,6 +341,7 @@ async fn handle_status(State(state): State<TtsApiState>) -> Json<StatusResponse>
         reminder_count: state.reminder.reminder_count(),
         queue_depth: state.queue_tx.max_capacity() - state.queue_tx.capacity(),
         deferred_count: state.deferred.lock().unwrap().len(),
+        pacing_gap_ms: state.pacer.current_gap_ms(),
     })
 }
 
@@ -329,6 +393,39 @@ async fn handle_speak(
     }
 }
 
+/// Structured voice description, mirrored by `mcp_server::VoiceInfo` so
+/// callers don't need to parse Kokoro's naming convention themselves.
+#[derive(Serialize)]
+struct VoiceInfo {
+    id: String,
+    name: String,
+    language: String,
+    gender: String,
+}
+
+/// Decode Kokoro's `{lang}{gender}_{name}` voice-id convention (e.g.
+/// `af_heart` = American female "heart") into a structured description.
+fn describe_kokoro_voice(id: &str) -> VoiceInfo {
+    let (language, gender) = match &id[..id.len().min(2)] {
+        "af" => ("en-US", "female"),
+        "am" => ("en-US", "male"),
+        "bf" => ("en-GB", "female"),
+        "bm" => ("en-GB", "male"),
+        _ => ("unknown", "unknown"),
+    };
+    let name = id.splitn(2, '_').nth(1).unwrap_or(id).to_string();
+    VoiceInfo {
+        id: id.to_string(),
+        name,
+        language: language.to_string(),
+        gender: gender.to_string(),
+    }
+}
+
+async fn handle_voices(State(state): State<TtsApiState>) -> Json<Vec<VoiceInfo>> {
+    Json(state.tts.list_voices().iter().map(|id| describe_kokoro_voice(id)).collect())
+}
+
 async fn handle_set_voice(
     State(state): State<TtsApiState>,
     Json(req): Json<SetVoiceRequest>,
@@ -433,8 +530,13 @@ async fn handle_disable(State(state): State<TtsApiState>) -> Json<SimpleResponse
     Json(SimpleResponse::ok("disabled"))
 }
 
+/// Outcome of a single `do_speak` run, passed back to the queue consumer.
+struct SpeakOutcome {
+    cancelled: bool,
+    playback_ms: f64,
+}
+
 /// Execute the speak pipeline: cancel reminder → interrupt stale speech → summarize → speak → reminder.
-/// Returns true if the speech was cancelled mid-playback.
 async fn do_speak(
     tts: &Arc<KokoroTtsEngine>,
     summarizer: &Arc<OllamaSummarizer>,
@@ -443,7 +545,7 @@ async fn do_speak(
     summarize: bool,
     event_type: String,
     start_reminder: bool,
-) -> bool {
+) -> SpeakOutcome {
     let t_total = std::time::Instant::now();
 
     // Cancel any existing reminder
@@ -500,5 +602,8 @@ async fn do_speak(
     };
     save_tts_record(&record);
 
-    result.cancelled
+    SpeakOutcome {
+        cancelled: result.cancelled,
+        playback_ms: result.playback_ms,
+    }
 }
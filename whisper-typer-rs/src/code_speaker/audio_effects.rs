@@ -0,0 +1,158 @@
+//! Post-processing audio effects applied to synthesized TTS PCM before
+//! playback, selected via `tts.filter` in config. Gives distinct,
+//! recognizable notification voices for different Claude Code hook events.
+
+use num_complex::Complex32;
+use realfft::RealFftPlanner;
+
+/// Overlap-add window size in samples.
+const WINDOW_SIZE: usize = 1024;
+/// 50% overlap between consecutive windows.
+const HOP_SIZE: usize = WINDOW_SIZE / 2;
+
+/// Which post-processing effect to apply to synthesized PCM before playback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AudioFilter {
+    #[default]
+    None,
+    /// Bandpass to ~300-3400Hz plus mild hard-clipping, like a two-way radio.
+    Radio,
+    /// Ring-modulated by a low-frequency sine carrier, like a vocoder robot.
+    Robot,
+    /// Voiced segments replaced by fixed-pitch tone bursts gated by the
+    /// amplitude envelope.
+    Blips,
+}
+
+/// Parse the `tts.filter` config string, defaulting to [`AudioFilter::None`]
+/// for anything unrecognized.
+pub fn parse_filter(name: &str) -> AudioFilter {
+    match name {
+        "radio" => AudioFilter::Radio,
+        "robot" => AudioFilter::Robot,
+        "blips" => AudioFilter::Blips,
+        _ => AudioFilter::None,
+    }
+}
+
+/// Apply `filter` to `samples` (mono, `sample_rate` Hz), returning the
+/// processed audio. A no-op for [`AudioFilter::None`].
+pub fn apply(filter: AudioFilter, samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    match filter {
+        AudioFilter::None => samples.to_vec(),
+        AudioFilter::Radio => radio_filter(samples, sample_rate),
+        AudioFilter::Robot => robot_filter(samples, sample_rate),
+        AudioFilter::Blips => blips_filter(samples, sample_rate),
+    }
+}
+
+fn hann_window(size: usize) -> Vec<f32> {
+    (0..size)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (size - 1) as f32).cos())
+        .collect()
+}
+
+/// Bandpass the signal to roughly the telephone/radio voice band
+/// (300-3400Hz) via overlap-add FFT filtering over Hann-windowed frames,
+/// then apply mild hard-clipping for squelch character.
+fn radio_filter(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    let window = hann_window(WINDOW_SIZE);
+    let mut planner = RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(WINDOW_SIZE);
+    let ifft = planner.plan_fft_inverse(WINDOW_SIZE);
+
+    let bin_hz = sample_rate as f32 / WINDOW_SIZE as f32;
+    let low_bin = (300.0 / bin_hz).round() as usize;
+    let high_bin = (3400.0 / bin_hz).round() as usize;
+
+    let mut output = vec![0.0f32; samples.len() + WINDOW_SIZE];
+    let mut window_energy = vec![0.0f32; samples.len() + WINDOW_SIZE];
+
+    let mut pos = 0;
+    while pos < samples.len() {
+        let take = WINDOW_SIZE.min(samples.len() - pos);
+        let mut frame = vec![0.0f32; WINDOW_SIZE];
+        for i in 0..take {
+            frame[i] = samples[pos + i] * window[i];
+        }
+
+        let mut spectrum = fft.make_output_vec();
+        fft.process(&mut frame, &mut spectrum).expect("forward FFT");
+
+        for (bin, value) in spectrum.iter_mut().enumerate() {
+            if bin < low_bin || bin > high_bin {
+                *value = Complex32::new(0.0, 0.0);
+            }
+        }
+
+        let mut time_domain = ifft.make_output_vec();
+        ifft.process(&mut spectrum, &mut time_domain).expect("inverse FFT");
+
+        // realfft's inverse transform is unnormalized: scale by window length.
+        let scale = 1.0 / WINDOW_SIZE as f32;
+        for i in 0..WINDOW_SIZE {
+            output[pos + i] += time_domain[i] * scale * window[i];
+            window_energy[pos + i] += window[i] * window[i];
+        }
+
+        pos += HOP_SIZE;
+    }
+
+    output.truncate(samples.len());
+    window_energy.truncate(samples.len());
+
+    // Normalize out the overlap-add window energy, then clip mildly for the
+    // squelch character, normalizing back to avoid gain loss.
+    const CLIP: f32 = 0.6;
+    output
+        .iter()
+        .zip(window_energy.iter())
+        .map(|(sample, energy)| {
+            let normalized = if *energy > 1e-6 { sample / energy } else { *sample };
+            normalized.clamp(-CLIP, CLIP) / CLIP
+        })
+        .collect()
+}
+
+/// Ring-modulate by multiplying with a low-frequency sine carrier, producing
+/// a metallic "robot" timbre.
+fn robot_filter(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    const CARRIER_HZ: f32 = 60.0;
+    let step = 2.0 * std::f32::consts::PI * CARRIER_HZ / sample_rate as f32;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, s)| s * (step * i as f32).sin())
+        .collect()
+}
+
+/// Compute a short-window amplitude envelope and replace voiced segments
+/// with fixed-pitch tone bursts gated by that envelope, silencing the rest.
+fn blips_filter(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    const TONE_HZ: f32 = 440.0;
+    const VOICED_THRESHOLD: f32 = 0.02;
+
+    let step = 2.0 * std::f32::consts::PI * TONE_HZ / sample_rate as f32;
+    let mut output = vec![0.0f32; samples.len()];
+
+    let mut pos = 0;
+    while pos < samples.len() {
+        let end = (pos + HOP_SIZE).min(samples.len());
+        let frame = &samples[pos..end];
+        let envelope = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+
+        if envelope > VOICED_THRESHOLD {
+            for (i, slot) in output[pos..end].iter_mut().enumerate() {
+                *slot = envelope * (step * (pos + i) as f32).sin();
+            }
+        }
+
+        pos = end;
+    }
+
+    output
+}
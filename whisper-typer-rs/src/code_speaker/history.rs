@@ -10,6 +10,8 @@ use chrono::Local;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
+use crate::history::csv_escape;
+
 fn history_dir() -> PathBuf {
     dirs::home_dir()
         .expect("No home directory")
@@ -99,6 +101,58 @@ pub fn list_tts_dates() -> Vec<String> {
     dates
 }
 
+/// Output format for [`export_tts_records`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Human-readable summary, same as [`generate_tts_report`].
+    Markdown,
+    /// Header row of record fields, for spreadsheets.
+    Csv,
+    /// JSON array, for programmatic consumers.
+    Json,
+    /// Compact binary encoding, for archival.
+    MessagePack,
+}
+
+/// Re-read `date`'s TTS records and serialize them in the requested format.
+pub fn export_tts_records(date: &str, format: ExportFormat) -> Result<Vec<u8>, String> {
+    match format {
+        ExportFormat::Markdown => Ok(generate_tts_report(date).into_bytes()),
+        ExportFormat::Json => serde_json::to_vec_pretty(&load_tts_records(date))
+            .map_err(|e| format!("Failed to serialize TTS records as JSON: {e}")),
+        ExportFormat::Csv => Ok(export_tts_records_csv(date).into_bytes()),
+        ExportFormat::MessagePack => rmp_serde::to_vec(&load_tts_records(date))
+            .map_err(|e| format!("Failed to serialize TTS records as MessagePack: {e}")),
+    }
+}
+
+fn export_tts_records_csv(date: &str) -> String {
+    let records = load_tts_records(date);
+
+    let mut csv = String::from(
+        "timestamp,event_type,input_text_chars,summarized,summary_text,ollama_latency_ms,\
+         kokoro_latency_ms,playback_duration_ms,total_latency_ms,voice,cancelled,reminder_count\n",
+    );
+    for r in &records {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            csv_escape(&r.timestamp),
+            csv_escape(&r.event_type),
+            r.input_text_chars,
+            r.summarized,
+            csv_escape(&r.summary_text),
+            r.ollama_latency_ms,
+            r.kokoro_latency_ms,
+            r.playback_duration_ms,
+            r.total_latency_ms,
+            csv_escape(&r.voice),
+            r.cancelled,
+            r.reminder_count,
+        ));
+    }
+    csv
+}
+
 pub fn generate_tts_report(date: &str) -> String {
     let records = load_tts_records(date);
     if records.is_empty() {
@@ -136,3 +190,86 @@ pub fn generate_tts_report(date: &str) -> String {
 
     report
 }
+
+/// Aggregate report across every day in `[start, end]` (inclusive,
+/// `YYYY-MM-DD`): mean + p50/p95/p99 latencies, a per-voice breakdown, and a
+/// per-day cancellation-rate trend, so regressions show up across a week
+/// instead of requiring the user to eyeball one day's file at a time.
+pub fn generate_tts_report_range(start: &str, end: &str) -> String {
+    let dates: Vec<String> = list_tts_dates()
+        .into_iter()
+        .filter(|d| d.as_str() >= start && d.as_str() <= end)
+        .collect();
+
+    let by_day: Vec<(String, Vec<TTSRecord>)> = dates
+        .iter()
+        .map(|d| (d.clone(), load_tts_records(d)))
+        .collect();
+
+    let total: usize = by_day.iter().map(|(_, records)| records.len()).sum();
+    if total == 0 {
+        return format!("No TTS records between {start} and {end}.");
+    }
+
+    let all_records: Vec<&TTSRecord> = by_day.iter().flat_map(|(_, records)| records.iter()).collect();
+
+    let kokoro: Vec<f64> = all_records.iter().map(|r| r.kokoro_latency_ms as f64).collect();
+    let ollama: Vec<f64> = all_records.iter().map(|r| r.ollama_latency_ms as f64).collect();
+    let total_latency: Vec<f64> = all_records.iter().map(|r| r.total_latency_ms as f64).collect();
+
+    let mut report = format!(
+        "# TTS Report {start} to {end}\n\n\
+        - Days: {}\n\
+        - Total events: {total}\n\n\
+        ## Latency Percentiles\n",
+        dates.len(),
+    );
+    report.push_str(&percentile_line("Kokoro", &kokoro));
+    report.push_str(&percentile_line("Ollama", &ollama));
+    report.push_str(&percentile_line("Total", &total_latency));
+
+    let mut voice_counts = std::collections::HashMap::new();
+    for r in &all_records {
+        *voice_counts.entry(r.voice.as_str()).or_insert(0) += 1;
+    }
+    report.push_str("\n## Per-Voice Breakdown\n");
+    for (voice, count) in &voice_counts {
+        report.push_str(&format!("- {voice}: {count}\n"));
+    }
+
+    report.push_str("\n## Cancellation Rate by Day\n");
+    for (date, records) in &by_day {
+        if records.is_empty() {
+            continue;
+        }
+        let cancelled = records.iter().filter(|r| r.cancelled).count();
+        let rate = cancelled as f64 / records.len() as f64 * 100.0;
+        report.push_str(&format!("- {date}: {rate:.1}% ({cancelled}/{})\n", records.len()));
+    }
+
+    report
+}
+
+fn percentile_line(label: &str, values: &[f64]) -> String {
+    if values.is_empty() {
+        return format!("- {label}: no data\n");
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    format!(
+        "- {label}: mean={mean:.0}ms p50={:.0}ms p95={:.0}ms p99={:.0}ms\n",
+        percentile(values, 50.0),
+        percentile(values, 95.0),
+        percentile(values, 99.0),
+    )
+}
+
+/// Nearest-rank percentile: sort and index at `ceil(p/100 * n) - 1`.
+fn percentile(values: &[f64], p: f64) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+    let idx = ((p / 100.0 * n as f64).ceil() as usize)
+        .saturating_sub(1)
+        .min(n - 1);
+    sorted[idx]
+}
@@ -7,8 +7,10 @@
 //! - `summarizer`: Ollama text summarization for long TTS input
 //! - `reminder`: Periodic reminder manager
 //! - `history`: TTS event history and reporting
+//! - `audio_effects`: post-processing filters (radio/robot/blips) for TTS output
 
 pub mod api;
+pub mod audio_effects;
 #[allow(dead_code)]
 pub mod history;
 pub mod reminder;
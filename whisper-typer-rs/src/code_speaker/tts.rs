@@ -23,6 +23,7 @@ use rodio::{OutputStream, OutputStreamBuilder, Sink};
 use tokio::sync::Mutex as AsyncMutex;
 use tracing::{debug, info, warn};
 
+use crate::code_speaker::audio_effects::{self, AudioFilter};
 use crate::config::TTSConfig;
 
 const SAMPLE_RATE: u32 = 24000;
@@ -60,6 +61,9 @@ pub struct KokoroTtsEngine {
     voice: Mutex<String>,
     speed: f32,
 
+    // Post-processing effect applied to generated audio before playback
+    filter: AudioFilter,
+
     // Audio output (kept alive for process lifetime)
     // In rodio 0.21, OutputStream is the handle — no separate OutputStreamHandle
     output_stream: Option<OutputStream>,
@@ -96,6 +100,7 @@ impl KokoroTtsEngine {
             voices: HashMap::new(),
             voice: Mutex::new(config.voice.clone()),
             speed: config.speed,
+            filter: audio_effects::parse_filter(&config.filter),
             output_stream: None,
             cancel_flag: Arc::new(AtomicBool::new(false)),
             speaking: Arc::new(AtomicBool::new(false)),
@@ -233,6 +238,7 @@ impl KokoroTtsEngine {
                     continue;
                 }
             };
+            let samples = audio_effects::apply(self.filter, &samples, SAMPLE_RATE);
             let gen_ms = t_gen.elapsed().as_secs_f64() * 1000.0;
             total_gen_ms += gen_ms;
 
@@ -0,0 +1,79 @@
+//! Guided voice commands: short utterances matched against a grammar of
+//! trigger phrases and dispatched as editor/system actions instead of being
+//! typed verbatim — inspired by whisper.cpp's "guided transcription" mode.
+//! Configured via `.whisper/commands.yaml` (trigger phrase -> action),
+//! hot-reloaded the same way as `.whisper/vocabulary.txt`/`corrections.yaml`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::util::levenshtein;
+
+/// One action a matched command phrase can dispatch.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CommandAction {
+    /// Press a single named key, e.g. "Return", "Escape", "BackSpace".
+    KeyPress { key: String },
+    /// Press a modifier chord, e.g. modifiers: [Control], key: a.
+    KeyChord { modifiers: Vec<String>, key: String },
+    /// Switch `DictationService::output_mode` ("ollama"/"whisper"/"both").
+    SetOutputMode { mode: String },
+    /// Run a shell command. Runs as configured, same trust model as
+    /// `.whisper/corrections.yaml` — this is a local config file the user
+    /// wrote themselves, not untrusted input.
+    ExecShell { command: String },
+}
+
+/// Load `.whisper/commands.yaml` (trigger phrase -> action).
+pub fn load_commands() -> HashMap<String, CommandAction> {
+    let path = PathBuf::from(".whisper/commands.yaml");
+    match fs::read_to_string(&path) {
+        Ok(contents) => match serde_yml::from_str::<HashMap<String, CommandAction>>(&contents) {
+            Ok(map) => {
+                if !map.is_empty() {
+                    info!("Loaded {} voice commands from .whisper/commands.yaml", map.len());
+                }
+                map
+            }
+            Err(e) => {
+                warn!("Failed to parse .whisper/commands.yaml: {e}");
+                HashMap::new()
+            }
+        },
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Normalize an utterance for command matching: trim, drop trailing
+/// punctuation Whisper tends to add even to short commands, lowercase.
+pub fn normalize(text: &str) -> String {
+    text.trim().trim_end_matches(['.', '!', '?']).to_lowercase()
+}
+
+/// Match a normalized utterance against `commands`: exact match first, then
+/// (if `fuzzy_distance > 0`) the closest trigger within that many
+/// Levenshtein edits, so a minor misrecognition like "select oll" still
+/// hits "select all".
+pub fn match_command<'a>(
+    normalized: &str,
+    commands: &'a HashMap<String, CommandAction>,
+    fuzzy_distance: usize,
+) -> Option<&'a CommandAction> {
+    if let Some(action) = commands.get(normalized) {
+        return Some(action);
+    }
+    if fuzzy_distance == 0 {
+        return None;
+    }
+    commands
+        .iter()
+        .map(|(trigger, action)| (levenshtein(normalized, trigger), action))
+        .filter(|(distance, _)| *distance <= fuzzy_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, action)| action)
+}
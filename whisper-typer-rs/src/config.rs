@@ -5,20 +5,36 @@
 
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tracing::info;
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct HotkeyConfig {
-    pub combo: Vec<String>,
-    pub alt_combos: Vec<Vec<String>>,
+    /// Primary combo, e.g. `"super + alt"`: `+`-separated, case-insensitive
+    /// key names. `super`/`ctrl`/`alt`/`shift` expand to either physical
+    /// key (left or right), so either one satisfies the combo. See
+    /// `hotkey::parse_combo` for the full key table and numeric fallback.
+    pub combo: String,
+    /// Additional combos that also trigger recording; the primary combo or
+    /// any one of these being fully pressed activates the hotkey.
+    pub alt_combos: Vec<String>,
+    /// Opt-in exclusive mode: `EVIOCGRAB` each monitored keyboard so combo
+    /// keys (e.g. the Meta/Alt held for recording) never leak through to
+    /// the focused application, forwarding everything else through a
+    /// uinput virtual device so normal typing still works. Off by default
+    /// since grabbing is disruptive if something goes wrong. See
+    /// `hotkey::HotkeyMonitor`.
+    pub grab: bool,
 }
 
 impl Default for HotkeyConfig {
     fn default() -> Self {
         Self {
-            combo: vec!["KEY_LEFTMETA".into(), "KEY_LEFTALT".into()],
+            combo: "super + alt".into(),
             alt_combos: vec![],
+            grab: false,
         }
     }
 }
@@ -27,18 +43,24 @@ impl Default for HotkeyConfig {
 #[serde(default)]
 pub struct AudioConfig {
     pub sample_rate: u32,
-    pub device_index: Option<u32>,
+    /// Input device to record from: a case-insensitive substring of its
+    /// name (e.g. "USB Mic"), `"#<index>"` for its position in
+    /// `AudioRecorder::list_input_devices` (as set by `--audio-device-index`,
+    /// see `Config::merge_args`), or `"default"` for the host's default
+    /// input device.
+    pub device: String,
+    /// Preferred channel count; the actual stream may use a different
+    /// count if the device doesn't support this one (see
+    /// `negotiate_input_config` in `recorder.rs`).
     pub channels: u16,
-    pub chunk_size: u32,
 }
 
 impl Default for AudioConfig {
     fn default() -> Self {
         Self {
             sample_rate: 16000,
-            device_index: None,
+            device: "default".to_string(),
             channels: 1,
-            chunk_size: 1024,
         }
     }
 }
@@ -47,21 +69,97 @@ impl Default for AudioConfig {
 #[serde(default)]
 pub struct RecordingConfig {
     pub max_duration: f64,
+    /// Live-caption the growing recording buffer — transcribe overlapping
+    /// windows while the hotkey is held and type words as they stabilize —
+    /// instead of waiting for the whole utterance. Off by default so the
+    /// existing batch path (one transcription on hotkey release) remains
+    /// the default. See `WhisperConfig::stability_threshold` for how
+    /// eagerly partial words get committed.
+    pub streaming: bool,
+    /// How often, in milliseconds, to re-run Whisper over the growing
+    /// buffer while `streaming` is enabled.
+    pub streaming_poll_interval_ms: u64,
 }
 
 impl Default for RecordingConfig {
     fn default() -> Self {
         Self {
             max_duration: 120.0,
+            streaming: false,
+            streaming_poll_interval_ms: 500,
         }
     }
 }
 
+/// How matched terms in `WhisperConfig::filter_words` are handled once a
+/// transcription is decoded, mirroring AWS Transcribe's vocabulary filter
+/// methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VocabularyFilterMethod {
+    /// Replace the matched term with `***`.
+    Mask,
+    /// Delete the matched term and collapse surrounding whitespace.
+    Remove,
+    /// Wrap the matched term in markers, e.g. `[term]`.
+    Tag,
+}
+
+impl Default for VocabularyFilterMethod {
+    fn default() -> Self {
+        Self::Mask
+    }
+}
+
+/// Whisper decoding strategy, selected at model load time.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum DecodingStrategy {
+    Greedy { best_of: i32 },
+    BeamSearch { beam_size: i32, patience: f32 },
+}
+
+impl Default for DecodingStrategy {
+    fn default() -> Self {
+        Self::Greedy { best_of: 1 }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WhisperConfig {
     pub model: String,
     pub device: String,
+    /// Number of consecutive streaming passes an item's text must survive
+    /// unchanged before it is considered "stable" and committed.
+    pub stability_threshold: u32,
+    /// Domain terms to bias decoding toward (identifiers, product names,
+    /// jargon). Concatenated into the Whisper initial prompt.
+    pub vocabulary: Vec<String>,
+    /// Freeform initial prompt, combined with `vocabulary` when non-empty.
+    pub initial_prompt: String,
+    /// Words/phrases to scrub from the final transcription (profanity,
+    /// names), matched case-insensitively after decoding.
+    pub filter_words: Vec<String>,
+    pub filter_method: VocabularyFilterMethod,
+    /// Language code to decode in, or "auto" to detect it from the leading
+    /// ~30s of audio before running the main decode pass.
+    pub language: String,
+    /// Sampling strategy used for the initial decode pass.
+    pub decoding: DecodingStrategy,
+    /// Temperatures to retry at, in order, when the initial decode's
+    /// average log-probability or compression ratio crosses its threshold
+    /// (mirrors whisper.cpp's temperature-fallback ladder).
+    pub temperature_fallback: Vec<f32>,
+    /// Below this average log-probability, the result is considered
+    /// low-confidence and a fallback retry is triggered.
+    pub logprob_threshold: f32,
+    /// Above this compression ratio, the result is considered repetitive
+    /// ("looping") and a fallback retry is triggered.
+    pub compression_ratio_threshold: f32,
+    /// Opt-in: enable whisper.cpp token timestamps and populate
+    /// `TranscribeResult::words` with per-token timing/confidence.
+    pub word_timestamps: bool,
 }
 
 impl Default for WhisperConfig {
@@ -69,16 +167,61 @@ impl Default for WhisperConfig {
         Self {
             model: "distil-whisper/distil-large-v3".into(),
             device: "cuda".into(),
+            stability_threshold: 2,
+            vocabulary: Vec::new(),
+            initial_prompt: String::new(),
+            filter_words: Vec::new(),
+            filter_method: VocabularyFilterMethod::default(),
+            language: "en".into(),
+            decoding: DecodingStrategy::default(),
+            temperature_fallback: vec![0.2, 0.4, 0.6, 0.8, 1.0],
+            logprob_threshold: -1.0,
+            compression_ratio_threshold: 2.4,
+            word_timestamps: false,
         }
     }
 }
 
+/// Text-correction backend selection, mirroring the multi-provider approach
+/// other LLM tools use so users without a local Ollama install can still
+/// get correction through a hosted model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CorrectionBackend {
+    Ollama,
+    OpenAi,
+    Anthropic,
+}
+
+impl Default for CorrectionBackend {
+    fn default() -> Self {
+        Self::Ollama
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct OllamaConfig {
     pub enabled: bool,
     pub model: String,
     pub host: String,
+    /// Which [`CorrectionBackend`] to send correction requests to.
+    pub backend: CorrectionBackend,
+    /// Bearer/`x-api-key` credential for hosted backends (unused by Ollama).
+    pub api_key: String,
+    /// Sampling temperature passed to the correction backend.
+    pub temperature: f32,
+    /// Max tokens to generate for the corrected text.
+    pub num_predict: i32,
+    /// Ollama context window size. Ollama exposes no API to query a model's
+    /// max tokens, so this must be set by hand for longer transcriptions.
+    pub num_ctx: i32,
+    /// If `model` isn't installed when `ensure_model_ready` runs, POST to
+    /// `/api/pull` and wait for it to download rather than erroring out.
+    pub auto_pull: bool,
+    /// Cap on correction requests dispatched per second, smoothed via a
+    /// leaky-bucket limiter shared across calls. `0.0` disables the limit.
+    pub max_requests_per_second: f32,
 }
 
 impl Default for OllamaConfig {
@@ -87,6 +230,13 @@ impl Default for OllamaConfig {
             enabled: true,
             model: "llama3.2:3b".into(),
             host: "http://localhost:11434".into(),
+            backend: CorrectionBackend::default(),
+            api_key: String::new(),
+            temperature: 0.1,
+            num_predict: 500,
+            num_ctx: 4096,
+            auto_pull: false,
+            max_requests_per_second: 0.0,
         }
     }
 }
@@ -95,12 +245,70 @@ impl Default for OllamaConfig {
 #[serde(default)]
 pub struct TyperConfig {
     pub backend: String,
+    /// Listening agent address for `backend = "remote"`, e.g.
+    /// "192.168.1.50:7890". Ignored by the enigo/xdotool backends.
+    pub remote_target: String,
+    /// Shared secret used to HMAC-sign every datagram sent to
+    /// `remote_target`, so the listening agent can reject forged or
+    /// replayed paste commands instead of trusting whatever hits its
+    /// socket. Required (a send fails without it) since the remote backend
+    /// has no other authentication.
+    pub remote_shared_secret: String,
 }
 
 impl Default for TyperConfig {
     fn default() -> Self {
         Self {
             backend: "ydotool".into(),
+            remote_target: String::new(),
+            remote_shared_secret: String::new(),
+        }
+    }
+}
+
+/// Runtime redaction list applied in `on_hotkey_release`, after Ollama
+/// correction but before the text is typed — lets users scrub names or
+/// profanity from dictated text without relying on the correction LLM.
+/// Loaded from `.whisper/vocabulary_filter.txt` (one word/phrase per line)
+/// and hot-reloaded the same way as `.whisper/vocabulary.txt`/`corrections.yaml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FilterConfig {
+    pub method: VocabularyFilterMethod,
+    /// Replacement text for `Tag`. Unlike `WhisperConfig`'s decode-time
+    /// `Tag` (which wraps the matched term), this marker replaces it
+    /// outright — a redaction filter that echoes back what it redacted
+    /// would defeat the point.
+    pub tag_marker: String,
+}
+
+impl Default for FilterConfig {
+    fn default() -> Self {
+        Self {
+            method: VocabularyFilterMethod::Mask,
+            tag_marker: "[filtered]".into(),
+        }
+    }
+}
+
+/// Guided voice commands: short utterances matched against
+/// `.whisper/commands.yaml`'s trigger phrases and dispatched as actions
+/// instead of being typed. See `crate::commands`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CommandsConfig {
+    pub enabled: bool,
+    /// Max Levenshtein distance allowed for a fuzzy match against a trigger
+    /// phrase when there's no exact match, e.g. so "select oll" still hits
+    /// "select all". `0` requires an exact match.
+    pub fuzzy_distance: usize,
+}
+
+impl Default for CommandsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fuzzy_distance: 2,
         }
     }
 }
@@ -124,10 +332,31 @@ impl Default for FeedbackConfig {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SilenceConfig {
+    /// Final RMS gate applied to the whole utterance after recording stops
+    /// (see `AudioRecorder::is_silent`), independent of the auto-stop VAD
+    /// below.
     pub threshold: f32,
+    /// Auto-stop hangover: how long a run of consecutive VAD-unvoiced
+    /// frames must last (after at least one voiced frame) before the
+    /// recording is considered finished.
     pub duration: f64,
     pub min_speech_duration: f64,
     pub max_recording_duration: f64,
+    /// WebRTC VAD aggressiveness, 0 (least aggressive, fewest false
+    /// "unvoiced" misclassifications) to 3 (most aggressive at filtering
+    /// out non-speech). Only used in `SilenceMode::Energy`.
+    pub vad_aggressiveness: u8,
+    /// VAD frame size in milliseconds; WebRTC VAD only accepts 10, 20, or
+    /// 30. Only used in `SilenceMode::Energy`.
+    pub vad_frame_ms: u32,
+    /// Which auto-stop VAD engine classifies frames as speech/non-speech.
+    /// `Spectral` is more robust to steady background noise (hum, fans,
+    /// keyboard clatter) that isn't simple broadband hiss; `Energy` is the
+    /// original WebRTC-VAD-driven amplitude gate. See `crate::vad`.
+    pub mode: SilenceMode,
+    /// Frequency band, in Hz, `SilenceMode::Spectral` treats as speech
+    /// when computing its in-band energy ratio.
+    pub speech_band_hz: (f32, f32),
 }
 
 impl Default for SilenceConfig {
@@ -137,6 +366,87 @@ impl Default for SilenceConfig {
             duration: 1.5,
             min_speech_duration: 0.5,
             max_recording_duration: 30.0,
+            vad_aggressiveness: 2,
+            vad_frame_ms: 30,
+            mode: SilenceMode::default(),
+            speech_band_hz: (300.0, 3000.0),
+        }
+    }
+}
+
+/// Auto-stop frame classifier selected by `SilenceConfig::mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SilenceMode {
+    /// The original WebRTC-VAD amplitude gate (`vad_aggressiveness`,
+    /// `vad_frame_ms`).
+    Energy,
+    /// FFT-based in-band energy ratio against an adaptive noise floor
+    /// (`speech_band_hz`); steadier against hum and keyboard clatter than
+    /// a broadband amplitude threshold.
+    Spectral,
+}
+
+impl Default for SilenceMode {
+    fn default() -> Self {
+        SilenceMode::Energy
+    }
+}
+
+/// Optional audio cleanup applied to a finished utterance before the
+/// silence gate and transcription (see `preprocess::process`), modeled on
+/// WebRTC's capture pipeline: high-pass filter, noise-floor subtraction,
+/// and automatic gain control. Off by default — the raw captured audio is
+/// already good enough for most microphones and rooms.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PreprocessConfig {
+    pub enabled: bool,
+    /// High-pass cutoff in Hz; removes DC offset and sub-audible rumble.
+    /// `0.0` disables the filter even when `enabled` is true.
+    pub high_pass_cutoff_hz: f32,
+    /// Strength of noise-floor subtraction, `0.0` (off) to `1.0` (full
+    /// subtraction of the estimated noise floor each frame).
+    pub noise_suppression_strength: f32,
+    /// RMS level the AGC drives the signal toward.
+    pub agc_target_rms: f32,
+    /// How quickly the AGC gain drops when the signal is louder than
+    /// `agc_target_rms`, in milliseconds.
+    pub agc_attack_ms: f64,
+    /// How quickly the AGC gain recovers when the signal is quieter than
+    /// `agc_target_rms`, in milliseconds.
+    pub agc_release_ms: f64,
+}
+
+impl Default for PreprocessConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            high_pass_cutoff_hz: 80.0,
+            noise_suppression_strength: 0.5,
+            agc_target_rms: 0.2,
+            agc_attack_ms: 50.0,
+            agc_release_ms: 300.0,
+        }
+    }
+}
+
+/// Per-utterance audio archival to `~/.cache/whisper-typer/audio/`, for
+/// later re-transcription (e.g. after a model upgrade) and debugging of
+/// missed dictations. Disabled by default since it uses disk space.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AudioArchiveConfig {
+    pub enabled: bool,
+    /// Oldest files beyond this count are pruned after each save.
+    pub max_files: usize,
+}
+
+impl Default for AudioArchiveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_files: 500,
         }
     }
 }
@@ -151,6 +461,19 @@ pub struct TTSConfig {
     pub max_direct_chars: usize,
     pub reminder_interval: u64,
     pub model_path: String,
+    /// Target fraction of time the audio device should spend speaking vs.
+    /// idle between queued jobs (the "tranquilizer" pacing target).
+    pub pacing_duty_cycle: f32,
+    /// Floor on the adaptive inter-utterance gap, regardless of how short
+    /// recent utterances have been.
+    pub pacing_min_gap_ms: u64,
+    /// Ceiling on the adaptive inter-utterance gap, regardless of how long
+    /// recent utterances have been.
+    pub pacing_max_gap_ms: u64,
+    /// Post-processing effect applied to synthesized audio before playback:
+    /// "none" (default), "radio", "robot", or "blips". Gives distinct,
+    /// recognizable notification voices for different hook events.
+    pub filter: String,
 }
 
 impl Default for TTSConfig {
@@ -163,6 +486,10 @@ impl Default for TTSConfig {
             max_direct_chars: 150,
             reminder_interval: 300,
             model_path: String::new(),
+            pacing_duty_cycle: 0.7,
+            pacing_min_gap_ms: 150,
+            pacing_max_gap_ms: 4000,
+            filter: "none".into(),
         }
     }
 }
@@ -183,6 +510,48 @@ impl Default for McpConfig {
     }
 }
 
+/// Local OpenAI-compatible transcription HTTP endpoint
+/// (`POST /v1/audio/transcriptions`), so other tools (editors, scripts) can
+/// reuse the already-loaded Whisper model instead of spawning a second one.
+/// Disabled by default since it opens a local port.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ApiConfig {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 8768,
+        }
+    }
+}
+
+/// OpenTelemetry OTLP export of dictation latency metrics. Disabled by
+/// default so the common build has no collector dependency at runtime.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    /// OTLP gRPC collector endpoint, e.g. "http://localhost:4317".
+    pub otlp_endpoint: String,
+    /// `service.name` resource attribute reported to the collector.
+    pub service_name: String,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            otlp_endpoint: "http://localhost:4317".into(),
+            service_name: "whisper-typer".into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct Config {
@@ -192,48 +561,425 @@ pub struct Config {
     pub whisper: WhisperConfig,
     pub ollama: OllamaConfig,
     pub typer: TyperConfig,
+    pub filter: FilterConfig,
+    pub commands: CommandsConfig,
     pub feedback: FeedbackConfig,
     pub silence: SilenceConfig,
+    pub preprocess: PreprocessConfig,
+    pub audio_archive: AudioArchiveConfig,
     pub tts: TTSConfig,
     pub mcp: McpConfig,
+    pub api: ApiConfig,
+    pub telemetry: TelemetryConfig,
 }
 
 impl Config {
-    /// Load configuration from YAML file.
+    /// Load configuration, layering defaults < file < environment.
+    ///
+    /// Searches standard locations for the file if no path is provided,
+    /// trying `config.yaml`, `config.toml`, and `config.json` in each:
+    /// 1. ./
+    /// 2. ~/.config/whisper-input/
+    /// 3. /etc/whisper-input/
+    ///
+    /// The format is picked by file extension (see `ConfigFormat`), so
+    /// users can keep their hotkey and audio settings in whichever of the
+    /// three they prefer — all the `#[serde(default)]`s below apply the
+    /// same way regardless of format.
     ///
-    /// Searches standard locations if no path is provided:
-    /// 1. ./config.yaml
-    /// 2. ~/.config/whisper-input/config.yaml
-    /// 3. /etc/whisper-input/config.yaml
+    /// After the file (if any) is parsed, `WHISPER_TYPER_`-prefixed
+    /// environment variables are overlaid on top — see
+    /// `apply_env_overrides` — so containerized and systemd deployments
+    /// can override individual fields without editing a file on disk.
     pub fn load(path: Option<&Path>) -> Self {
-        let resolved = path.map(PathBuf::from).or_else(|| {
-            let candidates = [
-                std::env::current_dir().ok().map(|d| d.join("config.yaml")),
-                dirs::home_dir().map(|h| h.join(".config/whisper-input/config.yaml")),
-                Some(PathBuf::from("/etc/whisper-input/config.yaml")),
-            ];
-            candidates.into_iter().flatten().find(|p| p.exists())
-        });
-
-        let Some(config_path) = resolved else {
-            info!("No config file found, using defaults");
-            return Self::default();
-        };
+        let resolved = resolve_config_path(path);
 
-        match std::fs::read_to_string(&config_path) {
-            Ok(contents) => match serde_yml::from_str(&contents) {
-                Ok(config) => {
-                    info!("Loaded config from {}", config_path.display());
-                    config
-                }
+        let mut value = match resolved {
+            Some((ref p, format)) => match std::fs::read_to_string(p) {
+                Ok(contents) => match format.parse(&contents) {
+                    Ok(v) => {
+                        info!("Loaded config from {}", p.display());
+                        v
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to parse {}: {e}, using defaults", p.display());
+                        serde_json::Value::Object(Default::default())
+                    }
+                },
                 Err(e) => {
-                    tracing::warn!("Failed to parse {}: {e}, using defaults", config_path.display());
-                    Self::default()
+                    tracing::warn!("Failed to read {}: {e}, using defaults", p.display());
+                    serde_json::Value::Object(Default::default())
                 }
             },
+            None => {
+                info!("No config file found, using defaults");
+                serde_json::Value::Object(Default::default())
+            }
+        };
+
+        apply_env_overrides(&mut value);
+
+        serde_json::from_value(value).unwrap_or_else(|e| {
+            tracing::warn!("Config invalid after environment overrides: {e}, using defaults");
+            Self::default()
+        })
+    }
+
+    /// Strict variant of `load` for a `--check-config` flag: a typo'd
+    /// top-level key or an out-of-range value returns a `ConfigError` naming
+    /// the offending field and file, instead of `load`'s "fall back to
+    /// defaults and warn" behavior, which is friendlier for a running
+    /// daemon but easy to miss in a terminal. Environment overrides are
+    /// applied the same way as `load` before validation, so the result
+    /// reflects what the daemon would actually run with. Unknown keys inside
+    /// a section (e.g. a typo under `silence:`) are not detected and are
+    /// ignored, as with `load`.
+    pub fn load_strict(path: Option<&Path>) -> Result<Config, ConfigError> {
+        let resolved = resolve_config_path(path);
+        let display_path = resolved.as_ref().map(|(p, _)| p.clone()).unwrap_or_else(|| PathBuf::from("<defaults>"));
+
+        let mut value = match resolved {
+            Some((ref p, format)) => {
+                let contents = std::fs::read_to_string(p)
+                    .map_err(|e| ConfigError::Io { path: p.clone(), source: e })?;
+                format
+                    .parse(&contents)
+                    .map_err(|message| ConfigError::Parse { path: p.clone(), message })?
+            }
+            None => serde_json::Value::Object(Default::default()),
+        };
+
+        check_unknown_top_level_fields(&value, &display_path)?;
+        apply_env_overrides(&mut value);
+
+        let config: Config = serde_json::from_value(value)
+            .map_err(|e| ConfigError::Parse { path: display_path, message: e.to_string() })?;
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Sanity-check value ranges that `serde` happily deserializes but
+    /// that would silently misbehave at runtime rather than fail to load
+    /// — e.g. an out-of-range `silence.threshold` just means every
+    /// recording either always or never fails the final silence gate. Only
+    /// `load_strict` calls this; `load` keeps its historical "never
+    /// refuses to start" behavior.
+    fn validate(&self) -> Result<(), ConfigError> {
+        const SANE_SAMPLE_RATES: &[u32] = &[8000, 16000, 22050, 24000, 32000, 44100, 48000];
+        if !SANE_SAMPLE_RATES.contains(&self.audio.sample_rate) {
+            return Err(ConfigError::Invalid {
+                field: "audio.sample_rate",
+                message: format!("{} Hz is not one of the commonly supported rates", self.audio.sample_rate),
+            });
+        }
+        if !(0.0..=1.0).contains(&self.silence.threshold) {
+            return Err(ConfigError::Invalid {
+                field: "silence.threshold",
+                message: format!("{} is outside the valid range 0.0..=1.0", self.silence.threshold),
+            });
+        }
+        let (band_lo, band_hi) = self.silence.speech_band_hz;
+        if band_lo < 0.0 || band_hi <= band_lo {
+            return Err(ConfigError::Invalid {
+                field: "silence.speech_band_hz",
+                message: format!("({band_lo}, {band_hi}) must be an increasing, non-negative (lo, hi) pair"),
+            });
+        }
+        if self.tts.speed <= 0.0 {
+            return Err(ConfigError::Invalid {
+                field: "tts.speed",
+                message: format!("{} must be greater than 0", self.tts.speed),
+            });
+        }
+        if self.whisper.model.trim().is_empty() {
+            return Err(ConfigError::Invalid {
+                field: "whisper.model",
+                message: "must not be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Like `load`, but also watches the resolved config path for changes
+    /// so edits (hotkey combo, silence thresholds, Ollama model, ...) take
+    /// effect without restarting the daemon: subsystems that want to pick
+    /// up live edits should hold the returned `ConfigHandle` and call
+    /// `current()` wherever they'd otherwise read a captured `Config`
+    /// snapshot (mirroring how `vocabulary`/`corrections`/`filter_terms`
+    /// already get polled for reload in `service.rs`).
+    ///
+    /// Returns `None` in place of the handle if no config file was found
+    /// to watch — there's nothing to watch, so callers keep using the
+    /// one-shot `Config` returned alongside it, same as `load`.
+    pub fn watch(path: Option<&Path>) -> (Self, Option<ConfigHandle>) {
+        let config = Self::load(path);
+        let Some((config_path, format)) = resolve_config_path(path) else {
+            return (config, None);
+        };
+
+        let inner = Arc::new(RwLock::new(config.clone()));
+        let handle = ConfigHandle { inner: Arc::clone(&inner) };
+
+        std::thread::spawn(move || watch_config_file(config_path, format, inner));
+
+        (config, Some(handle))
+    }
+
+    /// Apply CLI flag overrides on top of an already-loaded `Config`,
+    /// completing the defaults < file < env (`apply_env_overrides`) < cli
+    /// precedence chain. Only flags the user actually passed override
+    /// anything; an absent `--model`/`--device`/etc. leaves whatever
+    /// `load`/`load_strict` already resolved in place.
+    pub fn merge_args(&mut self, args: &crate::Args) {
+        if let Some(model) = &args.model {
+            self.whisper.model = model.clone();
+        }
+        if let Some(device) = &args.device {
+            self.whisper.device = device.clone();
+        }
+        if let Some(index) = args.audio_device_index {
+            self.audio.device = format!("#{index}");
+        }
+        if args.no_ollama {
+            self.ollama.enabled = false;
+        }
+        if let Some(backend) = &args.typer_backend {
+            self.typer.backend = backend.clone();
+        }
+    }
+}
+
+/// Config file formats recognized by file extension; `.yaml`/`.yml` is the
+/// long-standing default, `.toml` and `.json` reuse the same `Config`
+/// struct and `#[serde(default)]`s via their own serde backends.
+#[derive(Debug, Clone, Copy)]
+enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => ConfigFormat::Toml,
+            Some("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Yaml, // .yaml, .yml, or unrecognized
+        }
+    }
+
+    /// Parse `contents` into the `serde_json::Value` pivot that `load` and
+    /// `apply_env_overrides` operate on, regardless of source format.
+    fn parse(self, contents: &str) -> Result<serde_json::Value, String> {
+        match self {
+            ConfigFormat::Yaml => serde_yml::from_str::<serde_yml::Value>(contents)
+                .map_err(|e| e.to_string())
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string())),
+            ConfigFormat::Toml => toml::from_str::<toml::Value>(contents)
+                .map_err(|e| e.to_string())
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string())),
+            ConfigFormat::Json => serde_json::from_str(contents).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Top-level keys `Config` actually has, used by `check_unknown_top_level_fields`
+/// to catch a typo like `whissper:` under `Config::load_strict`.
+const KNOWN_TOP_LEVEL_FIELDS: &[&str] = &[
+    "hotkey", "audio", "recording", "whisper", "ollama", "typer", "filter", "commands",
+    "feedback", "silence", "preprocess", "audio_archive", "tts", "mcp", "api", "telemetry",
+];
+
+/// Reject any top-level config key that isn't one of `KNOWN_TOP_LEVEL_FIELDS`,
+/// the way `#[serde(deny_unknown_fields)]` would if it could be toggled
+/// per-call rather than fixed at compile time.
+fn check_unknown_top_level_fields(value: &serde_json::Value, path: &Path) -> Result<(), ConfigError> {
+    let Some(object) = value.as_object() else { return Ok(()) };
+    for key in object.keys() {
+        if !KNOWN_TOP_LEVEL_FIELDS.contains(&key.as_str()) {
+            return Err(ConfigError::UnknownField { path: path.to_path_buf(), key: key.clone() });
+        }
+    }
+    Ok(())
+}
+
+/// Structured error from `Config::load_strict`, naming the offending file
+/// and field so a `--check-config` flag can report something actionable
+/// instead of `load`'s silent fallback to defaults.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file couldn't be read at all.
+    Io { path: PathBuf, source: std::io::Error },
+    /// The contents didn't parse as the selected format.
+    Parse { path: PathBuf, message: String },
+    /// A top-level key isn't one `Config` recognizes — likely a typo.
+    UnknownField { path: PathBuf, key: String },
+    /// Parsed fine, but a value is out of range; see `Config::validate`.
+    Invalid { field: &'static str, message: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            ConfigError::Parse { path, message } => write!(f, "{}: {message}", path.display()),
+            ConfigError::UnknownField { path, key } => {
+                write!(f, "{}: unknown config key `{key}`", path.display())
+            }
+            ConfigError::Invalid { field, message } => write!(f, "{field}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Overlay `WHISPER_TYPER_<A>__<B>__...`-style environment variables onto a
+/// parsed config value, with `__` separating nested field path segments
+/// (case-insensitive) — e.g. `WHISPER_TYPER_WHISPER__MODEL=base.en`
+/// reaches `config.whisper.model`, `WHISPER_TYPER_TTS__ENABLED=true`
+/// reaches `config.tts.enabled`. Applied after the config file, so
+/// precedence is defaults < file < env.
+fn apply_env_overrides(value: &mut serde_json::Value) {
+    const PREFIX: &str = "WHISPER_TYPER_";
+    for (key, raw) in std::env::vars() {
+        let Some(path) = key.strip_prefix(PREFIX) else { continue };
+        let segments: Vec<String> = path.split("__").map(|s| s.to_lowercase()).collect();
+        if segments.iter().any(|s| s.is_empty()) {
+            continue;
+        }
+        set_path(value, &segments, parse_env_value(&raw));
+    }
+}
+
+/// Walk (creating as needed) the nested objects named by `segments`,
+/// setting the final segment to `new_value`.
+fn set_path(root: &mut serde_json::Value, segments: &[String], new_value: serde_json::Value) {
+    if !root.is_object() {
+        *root = serde_json::Value::Object(Default::default());
+    }
+    let object = root.as_object_mut().expect("just ensured this is an object");
+
+    if segments.len() == 1 {
+        object.insert(segments[0].clone(), new_value);
+        return;
+    }
+
+    let entry = object
+        .entry(segments[0].clone())
+        .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    set_path(entry, &segments[1..], new_value);
+}
+
+/// Parse an environment variable's raw string into a JSON scalar: booleans
+/// and numbers are recognized so e.g. `...__ENABLED=true` or
+/// `...__PORT=9000` coerce to the field's expected type instead of
+/// landing as the literal string `"true"`/`"9000"`.
+fn parse_env_value(raw: &str) -> serde_json::Value {
+    if let Ok(b) = raw.parse::<bool>() {
+        return serde_json::Value::Bool(b);
+    }
+    if let Ok(i) = raw.parse::<i64>() {
+        return serde_json::Value::Number(i.into());
+    }
+    if let Ok(f) = raw.parse::<f64>() {
+        if let Some(n) = serde_json::Number::from_f64(f) {
+            return serde_json::Value::Number(n);
+        }
+    }
+    serde_json::Value::String(raw.to_string())
+}
+
+/// Resolve a config path the same way `Config::load` does: the explicit
+/// `path`, or the first `config.{yaml,toml,json}` across the standard
+/// locations (in that per-location format order) that exists.
+fn resolve_config_path(path: Option<&Path>) -> Option<(PathBuf, ConfigFormat)> {
+    if let Some(p) = path {
+        let p = PathBuf::from(p);
+        let format = ConfigFormat::from_extension(&p);
+        return Some((p, format));
+    }
+
+    let dirs = [
+        std::env::current_dir().ok(),
+        dirs::home_dir().map(|h| h.join(".config/whisper-input")),
+        Some(PathBuf::from("/etc/whisper-input")),
+    ];
+
+    dirs.into_iter().flatten().find_map(|dir| {
+        ["config.yaml", "config.yml", "config.toml", "config.json"]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|p| p.exists())
+            .map(|p| {
+                let format = ConfigFormat::from_extension(&p);
+                (p, format)
+            })
+    })
+}
+
+/// Live handle on a watched config: cheap to clone, so any subsystem that
+/// needs to react to a saved edit can hold one and call `current()`
+/// instead of capturing a `Config` snapshot at startup. See `Config::watch`.
+#[derive(Clone)]
+pub struct ConfigHandle {
+    inner: Arc<RwLock<Config>>,
+}
+
+impl ConfigHandle {
+    /// Snapshot of the currently loaded config.
+    pub fn current(&self) -> Config {
+        self.inner.read().unwrap().clone()
+    }
+}
+
+/// Runs on its own thread for the lifetime of the process: blocks on the
+/// next filesystem event for `config_path`, debounces further events for
+/// 250ms (a single save can fire more than one event), then re-parses and
+/// swaps `inner` if it's still valid. A failed read or parse logs a
+/// warning and keeps serving the previous good config rather than
+/// reverting to defaults the way a failed *initial* load does — a
+/// half-saved edit shouldn't blow away a daemon that's been running fine
+/// for days.
+fn watch_config_file(config_path: PathBuf, format: ConfigFormat, inner: Arc<RwLock<Config>>) {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(w) => w,
+        Err(e) => {
+            tracing::warn!("Failed to start config watcher: {e}");
+            return;
+        }
+    };
+    if let Err(e) = notify::Watcher::watch(&mut watcher, &config_path, notify::RecursiveMode::NonRecursive) {
+        tracing::warn!("Failed to watch {}: {e}", config_path.display());
+        return;
+    }
+
+    loop {
+        let Ok(_first) = rx.recv() else { break };
+        while rx.recv_timeout(Duration::from_millis(250)).is_ok() {}
+
+        match std::fs::read_to_string(&config_path).map_err(|e| e.to_string()).and_then(|contents| format.parse(&contents)) {
+            Ok(mut value) => {
+                apply_env_overrides(&mut value);
+                match serde_json::from_value::<Config>(value) {
+                    Ok(reloaded) => {
+                        info!("Reloaded config from {}", config_path.display());
+                        *inner.write().unwrap() = reloaded;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to parse {} after change: {e}, keeping previous config",
+                            config_path.display()
+                        );
+                    }
+                }
+            }
             Err(e) => {
-                tracing::warn!("Failed to read {}: {e}, using defaults", config_path.display());
-                Self::default()
+                tracing::warn!(
+                    "Failed to read {} after change: {e}, keeping previous config",
+                    config_path.display()
+                );
             }
         }
     }
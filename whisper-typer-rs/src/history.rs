@@ -5,6 +5,7 @@
 
 use chrono::Local;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io::{BufRead, Write};
 use std::path::PathBuf;
@@ -27,6 +28,96 @@ fn history_file(date: &str) -> PathBuf {
     history_dir().join(format!("{date_str}.jsonl"))
 }
 
+/// Directory for archived per-utterance WAV files.
+fn audio_archive_dir() -> PathBuf {
+    dirs::home_dir()
+        .expect("No home directory")
+        .join(".cache/whisper-typer/audio")
+}
+
+/// Write `samples` (f32, `sample_rate`Hz, mono) to a timestamped WAV under
+/// [`audio_archive_dir`] and return its path, or `None` on failure. Follows
+/// the lasprs pattern of never creating a file for empty/silent captures —
+/// callers should only call this after the same `is_silent`/empty checks
+/// the dictation pipeline already runs, so the archive never fills with
+/// zero-content recordings. Prunes the oldest archived files beyond
+/// `max_files` (`config.audio_archive.max_files`) afterward.
+pub fn save_audio_wav(
+    samples: &[f32],
+    sample_rate: u32,
+    timestamp: &str,
+    max_files: usize,
+) -> Option<PathBuf> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let dir = audio_archive_dir();
+    if let Err(e) = fs::create_dir_all(&dir) {
+        error!("Failed to create audio archive dir: {e}");
+        return None;
+    }
+
+    let file_name = format!("{}.wav", timestamp.replace([':', '.'], "-"));
+    let path = dir.join(&file_name);
+
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let write_result = (|| -> Result<(), hound::Error> {
+        let mut writer = hound::WavWriter::create(&path, spec)?;
+        for &sample in samples {
+            writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+        }
+        writer.finalize()
+    })();
+
+    match write_result {
+        Ok(()) => {
+            debug!("Archived utterance audio to {}", path.display());
+            prune_audio_archive(max_files);
+            Some(path)
+        }
+        Err(e) => {
+            error!("Failed to write archived WAV {}: {e}", path.display());
+            let _ = fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+/// Delete the oldest archived WAVs beyond `max_files`, keyed by file name
+/// (timestamps sort lexicographically, so the newest names sort last).
+fn prune_audio_archive(max_files: usize) {
+    let dir = audio_archive_dir();
+    let mut files: Vec<PathBuf> = match fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "wav"))
+            .collect(),
+        Err(_) => return,
+    };
+
+    if files.len() <= max_files {
+        return;
+    }
+
+    files.sort();
+    let excess = files.len() - max_files;
+    for path in &files[..excess] {
+        if let Err(e) = fs::remove_file(path) {
+            error!("Failed to prune archived WAV {}: {e}", path.display());
+        } else {
+            debug!("Pruned archived WAV {}", path.display());
+        }
+    }
+}
+
 /// Record of a single transcription, matching the Python format exactly.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptionRecord {
@@ -43,6 +134,11 @@ pub struct TranscriptionRecord {
     pub char_count: usize,
     pub word_count: usize,
     pub speed_ratio: f64,
+    /// Path to the archived WAV of this utterance, when
+    /// `config.audio_archive.enabled`. `None` for records predating the
+    /// field or when the capture was empty/silent (never archived).
+    #[serde(default)]
+    pub audio_path: Option<String>,
 }
 
 /// Append a transcription record to the daily history file.
@@ -148,9 +244,28 @@ fn truncate(text: &str, max_len: usize) -> String {
     }
 }
 
-/// Generate a Markdown productivity report for a given date.
-pub fn generate_report(date: &str) -> String {
+/// Aggregate stats for a day's transcriptions, computed once and shared by
+/// every [`ReportFormat`] renderer so they can't drift out of sync with
+/// each other.
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyStats {
+    pub date: String,
+    pub records: Vec<TranscriptionRecord>,
+    pub total_chars: usize,
+    pub total_words: usize,
+    pub total_audio_s: f64,
+    pub total_processing_s: f64,
+    pub avg_whisper_ms: f64,
+    pub avg_ollama_ms: Option<f64>,
+    pub avg_typing_ms: f64,
+    pub avg_speed_ratio: f64,
+}
+
+fn compute_daily_stats(date: &str) -> Option<DailyStats> {
     let records = load_records(date);
+    if records.is_empty() {
+        return None;
+    }
 
     let display_date = if date == "today" {
         Local::now().format("%Y-%m-%d").to_string()
@@ -158,16 +273,10 @@ pub fn generate_report(date: &str) -> String {
         date.to_string()
     };
 
-    if records.is_empty() {
-        return format!(
-            "# WhisperTyper Report - {display_date}\n\nNo transcriptions recorded."
-        );
-    }
-
     let total_chars: usize = records.iter().map(|r| r.char_count).sum();
     let total_words: usize = records.iter().map(|r| r.word_count).sum();
-    let total_audio: f64 = records.iter().map(|r| r.audio_duration_s).sum();
-    let total_processing: f64 =
+    let total_audio_s: f64 = records.iter().map(|r| r.audio_duration_s).sum();
+    let total_processing_s: f64 =
         records.iter().map(|r| r.total_latency_ms as f64).sum::<f64>() / 1000.0;
 
     let whisper_latencies: Vec<i64> = records.iter().map(|r| r.whisper_latency_ms).collect();
@@ -177,46 +286,67 @@ pub fn generate_report(date: &str) -> String {
         .collect();
     let typing_latencies: Vec<i64> = records.iter().map(|r| r.typing_latency_ms).collect();
 
-    let avg_whisper = if whisper_latencies.is_empty() {
-        0.0
-    } else {
-        whisper_latencies.iter().sum::<i64>() as f64 / whisper_latencies.len() as f64
-    };
-    let avg_ollama = if ollama_latencies.is_empty() {
-        0.0
-    } else {
-        ollama_latencies.iter().sum::<i64>() as f64 / ollama_latencies.len() as f64
-    };
-    let avg_typing = if typing_latencies.is_empty() {
-        0.0
+    let avg_whisper_ms = whisper_latencies.iter().sum::<i64>() as f64 / whisper_latencies.len() as f64;
+    let avg_ollama_ms = if ollama_latencies.is_empty() {
+        None
     } else {
-        typing_latencies.iter().sum::<i64>() as f64 / typing_latencies.len() as f64
+        Some(ollama_latencies.iter().sum::<i64>() as f64 / ollama_latencies.len() as f64)
     };
-    let avg_speed: f64 =
+    let avg_typing_ms = typing_latencies.iter().sum::<i64>() as f64 / typing_latencies.len() as f64;
+    let avg_speed_ratio: f64 =
         records.iter().map(|r| r.speed_ratio).sum::<f64>() / records.len() as f64;
 
+    Some(DailyStats {
+        date: display_date,
+        records,
+        total_chars,
+        total_words,
+        total_audio_s,
+        total_processing_s,
+        avg_whisper_ms,
+        avg_ollama_ms,
+        avg_typing_ms,
+        avg_speed_ratio,
+    })
+}
+
+/// Output format for [`generate_report_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    /// Human-readable summary + table, same as [`generate_report`].
+    #[default]
+    Markdown,
+    /// Raw stats object, for programmatic consumers.
+    Json,
+    /// One row per transcription, for spreadsheets.
+    Csv,
+    /// Self-contained HTML page with a sortable table.
+    Html,
+}
+
+fn render_markdown(stats: &DailyStats) -> String {
     let mut lines = vec![
-        format!("# WhisperTyper Report - {display_date}"),
+        format!("# WhisperTyper Report - {}", stats.date),
         String::new(),
         "## Summary".to_string(),
-        format!("- **Transcriptions**: {}", records.len()),
-        format!("- **Total characters**: {total_chars}"),
-        format!("- **Total words**: {total_words}"),
-        format!("- **Total audio**: {}", format_duration(total_audio)),
+        format!("- **Transcriptions**: {}", stats.records.len()),
+        format!("- **Total characters**: {}", stats.total_chars),
+        format!("- **Total words**: {}", stats.total_words),
+        format!("- **Total audio**: {}", format_duration(stats.total_audio_s)),
         format!(
             "- **Total processing time**: {}",
-            format_duration(total_processing)
+            format_duration(stats.total_processing_s)
         ),
-        format!("- **Average speed ratio**: {avg_speed:.1}x"),
+        format!("- **Average speed ratio**: {:.1}x", stats.avg_speed_ratio),
         String::new(),
         "## Latency Averages".to_string(),
-        format!("- Whisper: {avg_whisper:.0}ms"),
+        format!("- Whisper: {:.0}ms", stats.avg_whisper_ms),
     ];
 
-    if !ollama_latencies.is_empty() {
+    if let Some(avg_ollama) = stats.avg_ollama_ms {
         lines.push(format!("- Ollama: {avg_ollama:.0}ms"));
     }
-    lines.push(format!("- Typing: {avg_typing:.0}ms"));
+    lines.push(format!("- Typing: {:.0}ms", stats.avg_typing_ms));
 
     lines.extend([
         String::new(),
@@ -226,7 +356,7 @@ pub fn generate_report(date: &str) -> String {
         "|------|---------|--------|-------|-------|".to_string(),
     ]);
 
-    for r in &records {
+    for r in &stats.records {
         let time_str = if r.timestamp.len() >= 19 {
             // Extract HH:MM:SS from ISO 8601 timestamp
             &r.timestamp[11..19]
@@ -248,3 +378,320 @@ pub fn generate_report(date: &str) -> String {
 
     lines.join("\n")
 }
+
+/// Quote a CSV field only when it needs it, matching how spreadsheet tools
+/// round-trip plain values without surrounding quotes.
+pub(crate) fn csv_escape(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn render_csv(stats: &DailyStats) -> String {
+    let mut csv = String::from(
+        "timestamp,kind,whisper_text,ollama_text,final_text,output_mode,whisper_latency_ms,ollama_latency_ms,typing_latency_ms,total_latency_ms,audio_duration_s,char_count,word_count,speed_ratio\n",
+    );
+    for r in &stats.records {
+        csv.push_str(&format!(
+            "{},stt,{},{},{},{},{},{},{},{},{},{},{},{:.2}\n",
+            csv_escape(&r.timestamp),
+            csv_escape(&r.whisper_text),
+            csv_escape(r.ollama_text.as_deref().unwrap_or("")),
+            csv_escape(&r.final_text),
+            csv_escape(&r.output_mode),
+            r.whisper_latency_ms,
+            r.ollama_latency_ms.map(|v| v.to_string()).unwrap_or_default(),
+            r.typing_latency_ms,
+            r.total_latency_ms,
+            r.audio_duration_s,
+            r.char_count,
+            r.word_count,
+            r.speed_ratio,
+        ));
+    }
+    csv
+}
+
+/// Escape text for embedding in the HTML report's table cells.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(stats: &DailyStats) -> String {
+    let mut rows = String::new();
+    for r in &stats.records {
+        let time_str = if r.timestamp.len() >= 19 {
+            &r.timestamp[11..19]
+        } else {
+            &r.timestamp[..8.min(r.timestamp.len())]
+        };
+        rows.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{:.0}</td><td>{}</td><td>{:.0}</td><td>{}</td><td>{}</td><td>{:.1}</td></tr>\n",
+            html_escape(time_str),
+            html_escape(&truncate(&r.final_text, 60)),
+            r.whisper_latency_ms,
+            r.ollama_latency_ms
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            r.typing_latency_ms,
+            r.char_count,
+            r.word_count,
+            r.speed_ratio,
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>WhisperTyper Report - {date}</title>
+<style>
+  body {{ font-family: sans-serif; margin: 2rem; }}
+  table {{ border-collapse: collapse; width: 100%; }}
+  th, td {{ border: 1px solid #ccc; padding: 0.4rem 0.6rem; text-align: left; }}
+  th {{ cursor: pointer; background: #f0f0f0; }}
+</style>
+</head>
+<body>
+<h1>WhisperTyper Report - {date}</h1>
+<p>{count} transcriptions, {chars} characters, {words} words</p>
+<table id="report">
+<thead><tr><th>Time</th><th>Text</th><th>Whisper (ms)</th><th>Ollama (ms)</th><th>Typing (ms)</th><th>Chars</th><th>Words</th><th>Speed</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<script>
+document.querySelectorAll('#report th').forEach((th, idx) => {{
+  th.addEventListener('click', () => {{
+    const tbody = th.closest('table').querySelector('tbody');
+    const rows = Array.from(tbody.querySelectorAll('tr'));
+    const asc = th.dataset.asc !== 'true';
+    rows.sort((a, b) => {{
+      const x = a.children[idx].innerText, y = b.children[idx].innerText;
+      const nx = parseFloat(x), ny = parseFloat(y);
+      const cmp = (!isNaN(nx) && !isNaN(ny)) ? nx - ny : x.localeCompare(y);
+      return asc ? cmp : -cmp;
+    }});
+    th.dataset.asc = asc;
+    rows.forEach(r => tbody.appendChild(r));
+  }});
+}});
+</script>
+</body>
+</html>
+"#,
+        date = stats.date,
+        count = stats.records.len(),
+        chars = stats.total_chars,
+        words = stats.total_words,
+        rows = rows,
+    )
+}
+
+/// Generate a Markdown productivity report for a given date.
+pub fn generate_report(date: &str) -> String {
+    generate_report_as(date, ReportFormat::Markdown)
+}
+
+/// Generate a productivity report for `date` in the requested format. Every
+/// format renders the same [`DailyStats`], so switching formats never
+/// changes which numbers are reported.
+pub fn generate_report_as(date: &str, format: ReportFormat) -> String {
+    let stats = match compute_daily_stats(date) {
+        Some(stats) => stats,
+        None => {
+            let display_date = if date == "today" {
+                Local::now().format("%Y-%m-%d").to_string()
+            } else {
+                date.to_string()
+            };
+            return match format {
+                ReportFormat::Json => {
+                    format!("{{\"date\":\"{display_date}\",\"records\":[]}}")
+                }
+                ReportFormat::Csv => {
+                    "timestamp,kind,whisper_text,ollama_text,final_text,output_mode,whisper_latency_ms,ollama_latency_ms,typing_latency_ms,total_latency_ms,audio_duration_s,char_count,word_count,speed_ratio\n".to_string()
+                }
+                ReportFormat::Markdown => format!(
+                    "# WhisperTyper Report - {display_date}\n\nNo transcriptions recorded."
+                ),
+                ReportFormat::Html => format!(
+                    "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>WhisperTyper Report - {display_date}</title></head><body><h1>WhisperTyper Report - {display_date}</h1><p>No transcriptions recorded.</p></body></html>"
+                ),
+            };
+        }
+    };
+
+    match format {
+        ReportFormat::Markdown => render_markdown(&stats),
+        ReportFormat::Json => serde_json::to_string_pretty(&stats)
+            .unwrap_or_else(|e| format!("{{\"error\":\"{e}\"}}")),
+        ReportFormat::Csv => render_csv(&stats),
+        ReportFormat::Html => render_html(&stats),
+    }
+}
+
+/// Common filler words tracked by [`compute_vocabulary_stats`].
+const FILLER_WORDS: &[&str] = &["um", "uh", "like", "you know", "so", "actually"];
+
+/// Stopwords skipped from the top-words table when `skip_stopwords` is set.
+const STOPWORDS: &[&str] = &[
+    "the", "a", "an", "and", "or", "but", "is", "are", "was", "were", "to", "of", "in", "on",
+    "for", "it", "that", "this", "with", "as", "at", "be", "i", "you",
+];
+
+/// Case-fold `text`, strip punctuation, and split into word tokens.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| {
+            w.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Word-frequency and speaking-rate analytics across a date range, computed
+/// once and shared by [`generate_vocabulary_report`] the same way
+/// [`DailyStats`] backs every [`ReportFormat`] renderer.
+#[derive(Debug, Clone, Serialize)]
+pub struct VocabularyStats {
+    pub start: String,
+    pub end: String,
+    pub days: usize,
+    pub total_words: usize,
+    pub unique_words: usize,
+    pub top_words: Vec<(String, u64)>,
+    pub filler_counts: Vec<(String, u64)>,
+    pub avg_wpm: f64,
+}
+
+/// Aggregate word-frequency and speaking-rate stats across every day in
+/// `[start, end]` (inclusive, `YYYY-MM-DD`), reusing
+/// [`list_available_dates`]/[`load_records`] so it sees the same data as the
+/// daily report. `top_n` caps the most-dictated words table; `skip_stopwords`
+/// filters common function words out of it.
+pub fn compute_vocabulary_stats(
+    start: &str,
+    end: &str,
+    top_n: usize,
+    skip_stopwords: bool,
+) -> Option<VocabularyStats> {
+    let dates: Vec<String> = list_available_dates()
+        .into_iter()
+        .filter(|d| d.as_str() >= start && d.as_str() <= end)
+        .collect();
+
+    let records: Vec<TranscriptionRecord> = dates.iter().flat_map(|d| load_records(d)).collect();
+    if records.is_empty() {
+        return None;
+    }
+
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    let mut filler_counts: HashMap<&str, u64> = FILLER_WORDS.iter().map(|f| (*f, 0)).collect();
+    let mut total_words = 0usize;
+
+    for r in &records {
+        let tokens = tokenize(&r.final_text);
+        total_words += tokens.len();
+        for token in &tokens {
+            if !(skip_stopwords && STOPWORDS.contains(&token.as_str())) {
+                *counts.entry(token.clone()).or_insert(0) += 1;
+            }
+        }
+        for filler in FILLER_WORDS {
+            // `tokens` never holds a multi-word string (`tokenize` splits on
+            // whitespace), so a phrase like "you know" is matched as a
+            // window of consecutive single-word tokens instead of a single
+            // token equality check.
+            let filler_words: Vec<&str> = filler.split_whitespace().collect();
+            let count = tokens
+                .windows(filler_words.len())
+                .filter(|w| w.iter().map(String::as_str).eq(filler_words.iter().copied()))
+                .count() as u64;
+            *filler_counts.get_mut(filler).unwrap() += count;
+        }
+    }
+
+    let unique_words = counts.len();
+    let mut top_words: Vec<(String, u64)> = counts.into_iter().collect();
+    top_words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top_words.truncate(top_n);
+
+    let mut filler_counts: Vec<(String, u64)> =
+        filler_counts.into_iter().map(|(f, c)| (f.to_string(), c)).collect();
+    filler_counts.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let total_audio_s: f64 = records.iter().map(|r| r.audio_duration_s).sum();
+    let avg_wpm = if total_audio_s > 0.0 {
+        total_words as f64 / (total_audio_s / 60.0)
+    } else {
+        0.0
+    };
+
+    Some(VocabularyStats {
+        start: start.to_string(),
+        end: end.to_string(),
+        days: dates.len(),
+        total_words,
+        unique_words,
+        top_words,
+        filler_counts,
+        avg_wpm,
+    })
+}
+
+/// Render a Markdown vocabulary/word-frequency report for `[start, end]`,
+/// using the default top-20 words and skipping stopwords.
+pub fn generate_vocabulary_report(start: &str, end: &str) -> String {
+    generate_vocabulary_report_with(start, end, 20, true)
+}
+
+/// Render a Markdown vocabulary/word-frequency report for `[start, end]`
+/// with an explicit `top_n` and `skip_stopwords` setting.
+pub fn generate_vocabulary_report_with(
+    start: &str,
+    end: &str,
+    top_n: usize,
+    skip_stopwords: bool,
+) -> String {
+    let stats = match compute_vocabulary_stats(start, end, top_n, skip_stopwords) {
+        Some(stats) => stats,
+        None => return format!("No transcriptions between {start} and {end}."),
+    };
+
+    let mut lines = vec![
+        format!("# Vocabulary Report {} to {}", stats.start, stats.end),
+        String::new(),
+        format!("- **Days**: {}", stats.days),
+        format!("- **Total words**: {}", stats.total_words),
+        format!("- **Unique vocabulary**: {}", stats.unique_words),
+        format!("- **Average speaking rate**: {:.0} wpm", stats.avg_wpm),
+        String::new(),
+        "## Filler Words".to_string(),
+    ];
+    for (word, count) in &stats.filler_counts {
+        lines.push(format!("- {word}: {count}"));
+    }
+
+    lines.extend([
+        String::new(),
+        format!("## Top {} Words", stats.top_words.len()),
+        String::new(),
+        "| Word | Count |".to_string(),
+        "|------|-------|".to_string(),
+    ]);
+    for (word, count) in &stats.top_words {
+        lines.push(format!("| {word} | {count} |"));
+    }
+
+    lines.join("\n")
+}
@@ -2,12 +2,36 @@
 //!
 //! Monitors all keyboard devices for configurable key combos.
 //! Sends press/release events via a tokio channel.
+//!
+//! Keyboards are expected to come and go: following rusty-keys' approach of
+//! an inotify watch on `/dev/input` (rather than re-polling on a timer),
+//! `HotkeyMonitor::run` picks up newly plugged-in keyboards at runtime and
+//! drops disconnected ones without tearing down the whole monitor. See
+//! `HotkeyStatus` for how that's surfaced to the service.
+//!
+//! Combos are configured with sohkd-style textual syntax (`"super + shift
+//! + r"`) rather than raw `KEY_*` arrays, parsed by `parse_combo` against a
+//! key table covering the full evdev keymap (media keys, numpad, locks,
+//! ...), with a numeric fallback for anything the table doesn't name. An
+//! unrecognized token is a hard config error at load time rather than a
+//! silently dropped key.
+//!
+//! With `HotkeyConfig::grab` set, combining rusty-keys' `EVIOCGRAB` and
+//! sohkd's per-hotkey consume flag: every monitored keyboard is grabbed
+//! exclusively so combo keys never reach the focused application, and
+//! every other keystroke is re-emitted through a uinput virtual keyboard
+//! (`build_passthrough`) so normal typing keeps working.
 
 use crate::config::HotkeyConfig;
-use evdev::{Device, EventType, InputEventKind, Key};
-use std::collections::HashSet;
+use evdev::uinput::{VirtualDevice, VirtualDeviceBuilder};
+use evdev::{AttributeSet, Device, EventType, InputEvent, InputEventKind, Key};
+use inotify::{EventMask, Inotify, WatchMask};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::StreamExt;
 use tracing::{debug, info, warn};
 
 /// Events sent from the hotkey monitor to the service.
@@ -17,73 +41,210 @@ pub enum HotkeyEvent {
     Released,
 }
 
-/// Resolve a key name like "KEY_LEFTMETA" to an evdev Key code.
-fn resolve_key(name: &str) -> Option<Key> {
-    // evdev::Key has a from_str-like constructor via the key code number.
-    // We need to map string names to codes manually for common keys.
-    let key = match name {
-        "KEY_LEFTMETA" => Key::KEY_LEFTMETA,
-        "KEY_RIGHTMETA" => Key::KEY_RIGHTMETA,
-        "KEY_LEFTALT" => Key::KEY_LEFTALT,
-        "KEY_RIGHTALT" => Key::KEY_RIGHTALT,
-        "KEY_LEFTCTRL" => Key::KEY_LEFTCTRL,
-        "KEY_RIGHTCTRL" => Key::KEY_RIGHTCTRL,
-        "KEY_LEFTSHIFT" => Key::KEY_LEFTSHIFT,
-        "KEY_RIGHTSHIFT" => Key::KEY_RIGHTSHIFT,
-        "KEY_PAGEDOWN" => Key::KEY_PAGEDOWN,
-        "KEY_PAGEUP" => Key::KEY_PAGEUP,
-        "KEY_RIGHT" => Key::KEY_RIGHT,
-        "KEY_LEFT" => Key::KEY_LEFT,
-        "KEY_UP" => Key::KEY_UP,
-        "KEY_DOWN" => Key::KEY_DOWN,
-        "KEY_SPACE" => Key::KEY_SPACE,
-        "KEY_ENTER" => Key::KEY_ENTER,
-        "KEY_TAB" => Key::KEY_TAB,
-        "KEY_ESC" => Key::KEY_ESC,
-        "KEY_A" => Key::KEY_A,
-        "KEY_B" => Key::KEY_B,
-        "KEY_C" => Key::KEY_C,
-        "KEY_D" => Key::KEY_D,
-        "KEY_E" => Key::KEY_E,
-        "KEY_F" => Key::KEY_F,
-        "KEY_G" => Key::KEY_G,
-        "KEY_H" => Key::KEY_H,
-        "KEY_I" => Key::KEY_I,
-        "KEY_J" => Key::KEY_J,
-        "KEY_K" => Key::KEY_K,
-        "KEY_L" => Key::KEY_L,
-        "KEY_M" => Key::KEY_M,
-        "KEY_N" => Key::KEY_N,
-        "KEY_O" => Key::KEY_O,
-        "KEY_P" => Key::KEY_P,
-        "KEY_Q" => Key::KEY_Q,
-        "KEY_R" => Key::KEY_R,
-        "KEY_S" => Key::KEY_S,
-        "KEY_T" => Key::KEY_T,
-        "KEY_U" => Key::KEY_U,
-        "KEY_V" => Key::KEY_V,
-        "KEY_W" => Key::KEY_W,
-        "KEY_X" => Key::KEY_X,
-        "KEY_Y" => Key::KEY_Y,
-        "KEY_Z" => Key::KEY_Z,
-        "KEY_F1" => Key::KEY_F1,
-        "KEY_F2" => Key::KEY_F2,
-        "KEY_F3" => Key::KEY_F3,
-        "KEY_F4" => Key::KEY_F4,
-        "KEY_F5" => Key::KEY_F5,
-        "KEY_F6" => Key::KEY_F6,
-        "KEY_F7" => Key::KEY_F7,
-        "KEY_F8" => Key::KEY_F8,
-        "KEY_F9" => Key::KEY_F9,
-        "KEY_F10" => Key::KEY_F10,
-        "KEY_F11" => Key::KEY_F11,
-        "KEY_F12" => Key::KEY_F12,
-        _ => {
-            warn!("Unknown key name: {name}");
-            return None;
-        }
-    };
-    Some(key)
+/// Keyboard connectivity updates, sent alongside `HotkeyEvent` so the
+/// service can log or notify when the hotkey stops being reachable —
+/// mirroring `recorder::AudioStatus` on the audio side.
+#[derive(Debug, Clone)]
+pub enum HotkeyStatus {
+    /// A keyboard device started being monitored (startup or hotplug).
+    DeviceConnected(String),
+    /// A keyboard device stopped being monitored (unplugged or read error).
+    DeviceDisconnected(String),
+}
+
+/// Key names recognized by `lookup_key`, covering the full evdev keymap
+/// (not just the handful of modifiers/letters the old hardcoded match
+/// knew about): letters, digits, function keys up to F24, navigation,
+/// numpad, punctuation, and media/lock keys. Keyed by the name with any
+/// `KEY_` prefix stripped and lowercased.
+fn key_table() -> &'static HashMap<&'static str, Key> {
+    use std::sync::OnceLock;
+    static TABLE: OnceLock<HashMap<&'static str, Key>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        HashMap::from([
+            ("leftmeta", Key::KEY_LEFTMETA),
+            ("rightmeta", Key::KEY_RIGHTMETA),
+            ("leftalt", Key::KEY_LEFTALT),
+            ("rightalt", Key::KEY_RIGHTALT),
+            ("leftctrl", Key::KEY_LEFTCTRL),
+            ("rightctrl", Key::KEY_RIGHTCTRL),
+            ("leftshift", Key::KEY_LEFTSHIFT),
+            ("rightshift", Key::KEY_RIGHTSHIFT),
+            ("capslock", Key::KEY_CAPSLOCK),
+            ("numlock", Key::KEY_NUMLOCK),
+            ("scrolllock", Key::KEY_SCROLLLOCK),
+            ("a", Key::KEY_A),
+            ("b", Key::KEY_B),
+            ("c", Key::KEY_C),
+            ("d", Key::KEY_D),
+            ("e", Key::KEY_E),
+            ("f", Key::KEY_F),
+            ("g", Key::KEY_G),
+            ("h", Key::KEY_H),
+            ("i", Key::KEY_I),
+            ("j", Key::KEY_J),
+            ("k", Key::KEY_K),
+            ("l", Key::KEY_L),
+            ("m", Key::KEY_M),
+            ("n", Key::KEY_N),
+            ("o", Key::KEY_O),
+            ("p", Key::KEY_P),
+            ("q", Key::KEY_Q),
+            ("r", Key::KEY_R),
+            ("s", Key::KEY_S),
+            ("t", Key::KEY_T),
+            ("u", Key::KEY_U),
+            ("v", Key::KEY_V),
+            ("w", Key::KEY_W),
+            ("x", Key::KEY_X),
+            ("y", Key::KEY_Y),
+            ("z", Key::KEY_Z),
+            ("0", Key::KEY_0),
+            ("1", Key::KEY_1),
+            ("2", Key::KEY_2),
+            ("3", Key::KEY_3),
+            ("4", Key::KEY_4),
+            ("5", Key::KEY_5),
+            ("6", Key::KEY_6),
+            ("7", Key::KEY_7),
+            ("8", Key::KEY_8),
+            ("9", Key::KEY_9),
+            ("f1", Key::KEY_F1),
+            ("f2", Key::KEY_F2),
+            ("f3", Key::KEY_F3),
+            ("f4", Key::KEY_F4),
+            ("f5", Key::KEY_F5),
+            ("f6", Key::KEY_F6),
+            ("f7", Key::KEY_F7),
+            ("f8", Key::KEY_F8),
+            ("f9", Key::KEY_F9),
+            ("f10", Key::KEY_F10),
+            ("f11", Key::KEY_F11),
+            ("f12", Key::KEY_F12),
+            ("f13", Key::KEY_F13),
+            ("f14", Key::KEY_F14),
+            ("f15", Key::KEY_F15),
+            ("f16", Key::KEY_F16),
+            ("f17", Key::KEY_F17),
+            ("f18", Key::KEY_F18),
+            ("f19", Key::KEY_F19),
+            ("f20", Key::KEY_F20),
+            ("f21", Key::KEY_F21),
+            ("f22", Key::KEY_F22),
+            ("f23", Key::KEY_F23),
+            ("f24", Key::KEY_F24),
+            ("space", Key::KEY_SPACE),
+            ("enter", Key::KEY_ENTER),
+            ("tab", Key::KEY_TAB),
+            ("esc", Key::KEY_ESC),
+            ("escape", Key::KEY_ESC),
+            ("backspace", Key::KEY_BACKSPACE),
+            ("insert", Key::KEY_INSERT),
+            ("delete", Key::KEY_DELETE),
+            ("home", Key::KEY_HOME),
+            ("end", Key::KEY_END),
+            ("pageup", Key::KEY_PAGEUP),
+            ("pagedown", Key::KEY_PAGEDOWN),
+            ("up", Key::KEY_UP),
+            ("down", Key::KEY_DOWN),
+            ("left", Key::KEY_LEFT),
+            ("right", Key::KEY_RIGHT),
+            ("pause", Key::KEY_PAUSE),
+            ("sysrq", Key::KEY_SYSRQ),
+            ("printscreen", Key::KEY_SYSRQ),
+            ("menu", Key::KEY_MENU),
+            ("compose", Key::KEY_COMPOSE),
+            ("power", Key::KEY_POWER),
+            ("sleep", Key::KEY_SLEEP),
+            ("wakeup", Key::KEY_WAKEUP),
+            ("minus", Key::KEY_MINUS),
+            ("equal", Key::KEY_EQUAL),
+            ("leftbrace", Key::KEY_LEFTBRACE),
+            ("rightbrace", Key::KEY_RIGHTBRACE),
+            ("semicolon", Key::KEY_SEMICOLON),
+            ("apostrophe", Key::KEY_APOSTROPHE),
+            ("grave", Key::KEY_GRAVE),
+            ("backslash", Key::KEY_BACKSLASH),
+            ("comma", Key::KEY_COMMA),
+            ("dot", Key::KEY_DOT),
+            ("slash", Key::KEY_SLASH),
+            ("kp0", Key::KEY_KP0),
+            ("kp1", Key::KEY_KP1),
+            ("kp2", Key::KEY_KP2),
+            ("kp3", Key::KEY_KP3),
+            ("kp4", Key::KEY_KP4),
+            ("kp5", Key::KEY_KP5),
+            ("kp6", Key::KEY_KP6),
+            ("kp7", Key::KEY_KP7),
+            ("kp8", Key::KEY_KP8),
+            ("kp9", Key::KEY_KP9),
+            ("kpplus", Key::KEY_KPPLUS),
+            ("kpminus", Key::KEY_KPMINUS),
+            ("kpasterisk", Key::KEY_KPASTERISK),
+            ("kpslash", Key::KEY_KPSLASH),
+            ("kpdot", Key::KEY_KPDOT),
+            ("kpenter", Key::KEY_KPENTER),
+            ("kpequal", Key::KEY_KPEQUAL),
+            ("playpause", Key::KEY_PLAYPAUSE),
+            ("nextsong", Key::KEY_NEXTSONG),
+            ("previoussong", Key::KEY_PREVIOUSSONG),
+            ("stopcd", Key::KEY_STOPCD),
+            ("ejectcd", Key::KEY_EJECTCD),
+            ("mute", Key::KEY_MUTE),
+            ("volumeup", Key::KEY_VOLUMEUP),
+            ("volumedown", Key::KEY_VOLUMEDOWN),
+            ("brightnessup", Key::KEY_BRIGHTNESSUP),
+            ("brightnessdown", Key::KEY_BRIGHTNESSDOWN),
+        ])
+    })
+}
+
+/// Resolve a single combo token (without alias expansion) to a Key: a name
+/// from `key_table` (with any `KEY_` prefix stripped, case-insensitive),
+/// or — covering whatever the table doesn't name — a raw numeric evdev
+/// keycode.
+fn lookup_key(token: &str) -> Option<Key> {
+    let normalized = token.trim().to_lowercase();
+    let stripped = normalized.strip_prefix("key_").unwrap_or(&normalized);
+
+    if let Ok(code) = stripped.parse::<u16>() {
+        return Some(Key::new(code));
+    }
+
+    key_table().get(stripped).copied()
+}
+
+/// Expand one combo token into the keys that satisfy it. `super`, `ctrl`,
+/// `alt`, and `shift` expand to both their left and right physical keys
+/// (sohkd's approach) so either side held down counts; anything else must
+/// resolve to exactly one key via `lookup_key`.
+fn expand_token(token: &str) -> Result<Vec<Key>, String> {
+    let lower = token.trim().to_lowercase();
+    match lower.as_str() {
+        "super" | "win" | "meta" => Ok(vec![Key::KEY_LEFTMETA, Key::KEY_RIGHTMETA]),
+        "ctrl" | "control" => Ok(vec![Key::KEY_LEFTCTRL, Key::KEY_RIGHTCTRL]),
+        "alt" => Ok(vec![Key::KEY_LEFTALT, Key::KEY_RIGHTALT]),
+        "shift" => Ok(vec![Key::KEY_LEFTSHIFT, Key::KEY_RIGHTSHIFT]),
+        _ => lookup_key(&lower).map(|k| vec![k]).ok_or_else(|| {
+            format!("Unknown key \"{token}\" in hotkey combo (see hotkey::key_table for valid names)")
+        }),
+    }
+}
+
+/// A parsed combo: one set of acceptable keys per `+`-separated token. The
+/// combo is active when every token's set has at least one key currently
+/// pressed — this is what lets `super + r` match either Meta key.
+type Combo = Vec<Vec<Key>>;
+
+/// Parse a textual combo like `"super + shift + r"` into a `Combo`.
+/// Returns an error naming the first unrecognized token, so a config typo
+/// fails loudly at startup rather than silently dropping a key.
+fn parse_combo(text: &str) -> Result<Combo, String> {
+    text.split('+').map(expand_token).collect()
+}
+
+fn combo_active(combo: &Combo, pressed: &HashSet<Key>) -> bool {
+    combo.iter().all(|alternatives| alternatives.iter().any(|k| pressed.contains(k)))
 }
 
 /// Shared state for tracking pressed keys across devices.
@@ -92,80 +253,142 @@ struct HotkeyState {
     hotkey_active: bool,
 }
 
+/// Keyboard devices currently being monitored, keyed by their `/dev/input`
+/// path so hotplug add/remove events can be deduplicated against what's
+/// already running.
+type MonitoredDevices = Arc<Mutex<HashMap<PathBuf, JoinHandle<()>>>>;
+
 pub struct HotkeyMonitor {
-    combos: Vec<HashSet<Key>>,
+    combos: Vec<Combo>,
+    /// Union of every key appearing in any combo. When `grab` is set these
+    /// are swallowed (not forwarded through the passthrough device) while
+    /// everything else is re-emitted so normal typing keeps working.
+    combo_keys: HashSet<Key>,
+    /// Opt-in exclusive mode — see the module doc and `HotkeyConfig::grab`.
+    grab: bool,
     state: Arc<Mutex<HotkeyState>>,
     tx: mpsc::Sender<HotkeyEvent>,
+    status_tx: mpsc::Sender<HotkeyStatus>,
 }
 
 impl HotkeyMonitor {
-    pub fn new(config: &HotkeyConfig, tx: mpsc::Sender<HotkeyEvent>) -> Self {
-        let mut combos = Vec::new();
-
-        // Primary combo
-        let primary: HashSet<Key> = config.combo.iter().filter_map(|s| resolve_key(s)).collect();
-        if !primary.is_empty() {
-            combos.push(primary);
-        }
-
-        // Alternate combos
+    /// Parse `config`'s combos. Returns an error (rather than silently
+    /// dropping the offending key) if any combo names a token `parse_combo`
+    /// doesn't recognize.
+    pub fn new(
+        config: &HotkeyConfig,
+        tx: mpsc::Sender<HotkeyEvent>,
+        status_tx: mpsc::Sender<HotkeyStatus>,
+    ) -> Result<Self, String> {
+        let mut combos = vec![parse_combo(&config.combo)?];
         for alt in &config.alt_combos {
-            let combo: HashSet<Key> = alt.iter().filter_map(|s| resolve_key(s)).collect();
-            if !combo.is_empty() {
-                combos.push(combo);
-            }
+            combos.push(parse_combo(alt)?);
         }
 
-        info!("Hotkey combos: {} configured", combos.len());
+        info!("Hotkey combos: {} configured ({:?})", combos.len(), config.combo);
 
-        Self {
+        let combo_keys = combos.iter().flatten().flatten().copied().collect();
+
+        Ok(Self {
             combos,
+            combo_keys,
+            grab: config.grab,
             state: Arc::new(Mutex::new(HotkeyState {
                 pressed_keys: HashSet::new(),
                 hotkey_active: false,
             })),
             tx,
-        }
+            status_tx,
+        })
     }
 
-    /// Find all keyboard input devices.
-    pub fn find_keyboards() -> Vec<Device> {
-        let mut keyboards = Vec::new();
+    /// Find all keyboard input devices currently present under `/dev/input`.
+    pub fn find_keyboards() -> Vec<(PathBuf, Device)> {
+        evdev::enumerate().filter(|(_path, device)| is_keyboard(device)).collect()
+    }
 
-        let devices = evdev::enumerate();
+    fn any_combo_active(combos: &[Combo], pressed: &HashSet<Key>) -> bool {
+        combos.iter().any(|combo| combo_active(combo, pressed))
+    }
 
-        for (_path, device) in devices {
-            let supported = device.supported_keys();
-            if let Some(keys) = supported {
-                if keys.contains(Key::KEY_A) && keys.contains(Key::KEY_ENTER) {
-                    info!("Found keyboard: {} at {:?}", device.name().unwrap_or("unknown"), device.physical_path());
-                    keyboards.push(device);
-                }
-            }
+    /// Spawn a monitor task for `device` if `path` isn't already tracked.
+    fn spawn_monitor(
+        &self,
+        path: PathBuf,
+        device: Device,
+        monitored: &MonitoredDevices,
+        passthrough: Option<Arc<Mutex<VirtualDevice>>>,
+    ) {
+        if monitored.lock().unwrap().contains_key(&path) {
+            return;
         }
 
-        keyboards
-    }
+        let name = device.name().unwrap_or("unknown").to_string();
+        info!("Monitoring keyboard: {name} at {path:?}");
+        let _ = self.status_tx.try_send(HotkeyStatus::DeviceConnected(name));
 
-    fn any_combo_active(combos: &[HashSet<Key>], pressed: &HashSet<Key>) -> bool {
-        combos.iter().any(|combo| combo.is_subset(pressed))
+        let combos = self.combos.clone();
+        let combo_keys = self.combo_keys.clone();
+        // Only actually grab if a passthrough device came up; grabbing
+        // without one would silently eat all normal typing.
+        let grab = self.grab && passthrough.is_some();
+        let state = Arc::clone(&self.state);
+        let tx = self.tx.clone();
+        let status_tx = self.status_tx.clone();
+        let monitored_for_task = Arc::clone(monitored);
+        let path_for_task = path.clone();
+
+        let handle = tokio::spawn(async move {
+            let device_name =
+                Self::monitor_device(device, combos, combo_keys, grab, passthrough, state, tx).await;
+            monitored_for_task.lock().unwrap().remove(&path_for_task);
+            let _ = status_tx.try_send(HotkeyStatus::DeviceDisconnected(device_name));
+        });
+
+        monitored.lock().unwrap().insert(path, handle);
     }
 
-    /// Monitor a single device for key events.
+    /// Monitor a single device for key events until it disconnects or its
+    /// event stream errors out. Returns the device's name for the
+    /// disconnect notification.
     async fn monitor_device(
-        device: Device,
-        combos: Vec<HashSet<Key>>,
+        mut device: Device,
+        combos: Vec<Combo>,
+        combo_keys: HashSet<Key>,
+        grab: bool,
+        passthrough: Option<Arc<Mutex<VirtualDevice>>>,
         state: Arc<Mutex<HotkeyState>>,
         tx: mpsc::Sender<HotkeyEvent>,
-    ) {
+    ) -> String {
         let name = device.name().unwrap_or("unknown").to_string();
         debug!("Monitoring {name}");
 
+        // Only this device's own grab succeeding means its events stop
+        // reaching the desktop directly, so only then should its
+        // non-combo keys be re-emitted through the passthrough below —
+        // otherwise a failed grab (already grabbed elsewhere, permission
+        // error, ...) would leave the original keystroke intact AND add a
+        // duplicate one via passthrough.
+        let grabbed = if grab {
+            match device.grab() {
+                Ok(()) => {
+                    info!("Grabbed {name} exclusively (combo keys will not reach other apps)");
+                    true
+                }
+                Err(e) => {
+                    warn!("Failed to grab {name} ({e}); combo keys may leak to other apps");
+                    false
+                }
+            }
+        } else {
+            false
+        };
+
         let mut events = match device.into_event_stream() {
             Ok(stream) => stream,
             Err(e) => {
                 warn!("Cannot create event stream for {name}: {e}");
-                return;
+                return name;
             }
         };
 
@@ -202,38 +425,152 @@ impl HotkeyMonitor {
                         debug!("Hotkey released");
                         let _ = tx.try_send(HotkeyEvent::Released);
                     }
+                    drop(state);
+
+                    // Grabbing the device stops its events reaching
+                    // anything else, so forward whatever isn't part of a
+                    // combo through the uinput passthrough to keep normal
+                    // typing working. Skip this entirely if the grab
+                    // itself failed — the device's own events still reach
+                    // the desktop, so forwarding too would double every
+                    // keystroke.
+                    if grabbed && !combo_keys.contains(&key) {
+                        if let Some(vdev) = &passthrough {
+                            let out = InputEvent::new(EventType::KEY, key.code(), value);
+                            if let Err(e) = vdev.lock().unwrap().emit(&[out]) {
+                                warn!("Failed to forward {key:?} through uinput passthrough: {e}");
+                            }
+                        }
+                    }
                 }
                 Err(e) => {
                     warn!("Device {name} disconnected: {e}");
+                    // The keys this device contributed are gone with it;
+                    // drop them so a stuck combo doesn't block forever.
+                    let mut state = state.lock().unwrap();
+                    state.pressed_keys.clear();
+                    if state.hotkey_active {
+                        state.hotkey_active = false;
+                        let _ = tx.try_send(HotkeyEvent::Released);
+                    }
                     break;
                 }
             }
         }
+
+        name
     }
 
-    /// Start monitoring all keyboards. Runs until all devices disconnect.
+    /// Start monitoring keyboards: whatever's plugged in at startup, plus
+    /// anything hotplugged afterwards. Runs forever — an empty keyboard
+    /// set is no longer fatal, since the `/dev/input` watch below can pick
+    /// one up later.
     pub async fn run(self) {
-        let keyboards = Self::find_keyboards();
-        if keyboards.is_empty() {
-            panic!(
-                "No keyboards found. Make sure you're in the 'input' group: \
-                 sudo usermod -aG input $USER"
+        let monitored: MonitoredDevices = Arc::new(Mutex::new(HashMap::new()));
+
+        let passthrough = if self.grab {
+            match build_passthrough() {
+                Ok(vdev) => Some(Arc::new(Mutex::new(vdev))),
+                Err(e) => {
+                    warn!("Cannot create uinput passthrough device ({e}); grab mode disabled");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        for (path, device) in Self::find_keyboards() {
+            self.spawn_monitor(path, device, &monitored, passthrough.clone());
+        }
+
+        if monitored.lock().unwrap().is_empty() {
+            warn!(
+                "No keyboards found yet (check you're in the 'input' group: \
+                 sudo usermod -aG input $USER) — waiting for one to be plugged in"
             );
         }
 
-        info!("Monitoring {} keyboard(s)", keyboards.len());
+        let inotify = match Inotify::init() {
+            Ok(i) => i,
+            Err(e) => {
+                warn!("Cannot watch /dev/input for hotplug ({e}); new keyboards will require a restart");
+                std::future::pending::<()>().await;
+                unreachable!();
+            }
+        };
 
-        let mut handles = Vec::new();
-        for device in keyboards {
-            let combos = self.combos.clone();
-            let state = Arc::clone(&self.state);
-            let tx = self.tx.clone();
-            handles.push(tokio::spawn(Self::monitor_device(device, combos, state, tx)));
+        if let Err(e) = inotify.watches().add("/dev/input", WatchMask::CREATE | WatchMask::DELETE) {
+            warn!("Cannot watch /dev/input for hotplug ({e}); new keyboards will require a restart");
+            std::future::pending::<()>().await;
+            unreachable!();
         }
 
-        // Wait for all monitors (they run until device disconnect)
-        for handle in handles {
-            let _ = handle.await;
+        let mut stream = match inotify.into_event_stream(vec![0; 1024]) {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Cannot read /dev/input hotplug events ({e}); new keyboards will require a restart");
+                std::future::pending::<()>().await;
+                unreachable!();
+            }
+        };
+
+        while let Some(event) = stream.next().await {
+            let Ok(event) = event else { continue };
+            let Some(file_name) = event.name else { continue };
+            let path = PathBuf::from("/dev/input").join(&file_name);
+
+            if event.mask.contains(EventMask::CREATE) {
+                // New device nodes take a moment to become readable after
+                // the CREATE event fires; a couple of retries covers it
+                // without needing a fixed sleep on the common case.
+                for attempt in 0..3 {
+                    match Device::open(&path) {
+                        Ok(device) if is_keyboard(&device) => {
+                            self.spawn_monitor(path.clone(), device, &monitored, passthrough.clone());
+                            break;
+                        }
+                        Ok(_) => break, // not a keyboard, ignore
+                        Err(_) if attempt < 2 => {
+                            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                        }
+                        Err(e) => {
+                            debug!("Could not open hotplugged {path:?}: {e}");
+                        }
+                    }
+                }
+            } else if event.mask.contains(EventMask::DELETE) {
+                // The corresponding `monitor_device` task will notice the
+                // read error on its own and clean itself up; nothing to do
+                // here beyond logging, since we don't track path->handle
+                // cancellation eagerly.
+                debug!("Device node removed: {path:?}");
+            }
         }
     }
 }
+
+/// Whether `device` looks like a keyboard: it must expose at least the
+/// alphabetic and Enter keys, which filters out mice/touchpads/etc. that
+/// also show up under `/dev/input`.
+fn is_keyboard(device: &Device) -> bool {
+    device
+        .supported_keys()
+        .map(|keys| keys.contains(Key::KEY_A) && keys.contains(Key::KEY_ENTER))
+        .unwrap_or(false)
+}
+
+/// Build a uinput virtual keyboard supporting every evdev key code, used
+/// to re-emit non-combo keystrokes while `HotkeyConfig::grab` has the real
+/// keyboard(s) grabbed exclusively.
+fn build_passthrough() -> std::io::Result<VirtualDevice> {
+    let mut keys = AttributeSet::<Key>::new();
+    for code in 0..768u16 {
+        keys.insert(Key::new(code));
+    }
+
+    VirtualDeviceBuilder::new()?
+        .name("whisper-typer-rs passthrough")
+        .with_keys(&keys)?
+        .build()
+}
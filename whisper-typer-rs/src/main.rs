@@ -1,16 +1,22 @@
 //! whisper-typer-rs: Speech-to-text dictation service for Linux.
 
+mod api;
 mod code_speaker;
+mod commands;
 mod config;
 mod history;
 mod hotkey;
 mod mcp_server;
 mod notifier;
+mod preprocess;
 mod processor;
 mod recorder;
 mod service;
+mod telemetry;
 mod transcriber;
 mod typer;
+mod util;
+mod vad;
 
 use clap::Parser;
 use std::path::PathBuf;
@@ -20,7 +26,7 @@ use tracing_subscriber::EnvFilter;
 
 #[derive(Parser, Debug)]
 #[command(name = "whisper-typer-rs", about = "Speech-to-text dictation service")]
-struct Args {
+pub struct Args {
     /// Path to config.yaml
     #[arg(short, long)]
     config: Option<PathBuf>,
@@ -36,12 +42,59 @@ struct Args {
     /// Enable verbose (debug) logging
     #[arg(short, long)]
     verbose: bool,
+
+    /// List available input audio devices and exit
+    #[arg(long)]
+    list_devices: bool,
+
+    /// Validate the config file strictly (unknown keys, out-of-range
+    /// values) and exit without starting the service.
+    #[arg(long)]
+    check_config: bool,
+
+    /// Override `whisper.model`, e.g. to try a different model for one run.
+    #[arg(long)]
+    model: Option<String>,
+
+    /// Override `whisper.device` (the Whisper compute device, e.g. "cpu" or
+    /// "cuda" — not the audio input device, see `--audio-device-index`).
+    #[arg(long)]
+    device: Option<String>,
+
+    /// Override `audio.device` by position in `--list-devices`' output
+    /// instead of by name.
+    #[arg(long)]
+    audio_device_index: Option<usize>,
+
+    /// Override `typer.backend`, e.g. "enigo", "xdotool", or "remote".
+    #[arg(long)]
+    typer_backend: Option<String>,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
+    if args.list_devices {
+        for name in recorder::AudioRecorder::list_input_devices() {
+            println!("{name}");
+        }
+        return Ok(());
+    }
+
+    if args.check_config {
+        match config::Config::load_strict(args.config.as_deref()) {
+            Ok(_) => {
+                println!("Config OK");
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("Config invalid: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Initialize logging (suppress noisy ort/rmcp internals)
     let filter = if args.verbose {
         EnvFilter::new("debug,ort=info,rmcp=info")
@@ -52,8 +105,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("whisper-typer-rs starting");
 
-    // Load config
-    let config = config::Config::load(args.config.as_deref());
+    // Load config and start watching it for edits, then layer CLI flag
+    // overrides on top of the initial snapshot (highest precedence — see
+    // `Config::merge_args`). `config_handle` is threaded into the service
+    // so a saved edit takes effect without restarting the daemon.
+    let (mut config, config_handle) = config::Config::watch(args.config.as_deref());
+    config.merge_args(&args);
     info!("Config loaded: {:?}", config.hotkey);
 
     // Determine output mode
@@ -79,8 +136,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         mcp_server::start_mcp_server(mcp_port, tts_port).await;
     }
 
+    // Optional OTLP export of dictation latency metrics (no-op unless configured)
+    let metrics = telemetry::init(&config.telemetry).map(Arc::new);
+
     // Run the service
-    let mut service = service::DictationService::new(config.clone(), transcriber, output_mode);
+    let mut service = service::DictationService::new(
+        config.clone(),
+        transcriber,
+        output_mode,
+        metrics,
+        config_handle,
+    );
 
     // Start native TTS server (replaces Python code-speaker.service)
     if config.tts.enabled {
@@ -106,6 +172,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     summarizer,
                     reminder,
                     max_direct_chars: config.tts.max_direct_chars,
+                    pacer: Arc::new(code_speaker::api::Pacer::new(&config.tts)),
                 };
                 code_speaker::api::start_tts_api(api_state, config.tts.api_port).await;
                 info!(
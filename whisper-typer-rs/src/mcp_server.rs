@@ -11,6 +11,7 @@ use std::fs;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use rmcp::handler::server::tool::{Parameters, ToolRouter};
@@ -22,8 +23,96 @@ use serde::Deserialize;
 use serde_json::json;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
+use tts::Tts;
 
 use crate::history;
+use crate::util::levenshtein;
+
+/// Which transport `code_speaker_*` tools use to reach a TTS engine: the
+/// Kokoro HTTP server (default, requires `code_speaker::tts` to be running)
+/// or the OS-native synthesizer via the `tts` crate (Speech Dispatcher on
+/// Linux, SAPI on Windows, AVSpeechSynthesizer on macOS), for machines that
+/// don't run Kokoro.
+#[derive(Clone)]
+pub enum TtsBackend {
+    Kokoro { port: u16 },
+    Native,
+}
+
+/// Wraps a native `tts::Tts` handle, kept alive for the life of the server
+/// rather than recreated per call. Voice enumeration is guarded against a
+/// panic — on Linux, a malformed Speech Dispatcher voice list has been
+/// known to unwind through `.voices()` — so a broken daemon yields an empty
+/// list instead of crashing the MCP tool handler.
+struct NativeTts {
+    tts: Mutex<Tts>,
+    enabled: std::sync::atomic::AtomicBool,
+}
+
+impl NativeTts {
+    fn new() -> Result<Self, String> {
+        let tts = Tts::default().map_err(|e| format!("Failed to init native TTS: {e}"))?;
+        Ok(Self {
+            tts: Mutex::new(tts),
+            enabled: std::sync::atomic::AtomicBool::new(true),
+        })
+    }
+
+    fn voices(&self) -> Vec<tts::Voice> {
+        let tts = self.tts.lock().unwrap();
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tts.voices()))
+            .ok()
+            .and_then(|r| r.ok())
+            .unwrap_or_default()
+    }
+
+    fn speak(&self, text: &str) -> Result<(), String> {
+        if !self.enabled.load(std::sync::atomic::Ordering::Relaxed) {
+            return Ok(());
+        }
+        let mut tts = self.tts.lock().unwrap();
+        tts.speak(text, true)
+            .map(|_| ())
+            .map_err(|e| format!("Native TTS speak failed: {e}"))
+    }
+
+    fn set_voice(&self, voice_id: &str) -> Result<(), String> {
+        let tts = self.tts.lock().unwrap();
+        let voices = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tts.voices()))
+            .ok()
+            .and_then(|r| r.ok())
+            .unwrap_or_default();
+        let voice = voices
+            .into_iter()
+            .find(|v| v.id() == voice_id)
+            .ok_or_else(|| format!("Unknown native voice: {voice_id}"))?;
+        tts.set_voice(&voice).map_err(|e| format!("Failed to set native voice: {e}"))
+    }
+}
+
+/// Structured voice description returned by [`WhisperTyperMcp::fetch_voices`],
+/// shared shape with `code_speaker::api::VoiceInfo` (the Kokoro HTTP side)
+/// so both backends render identically in `code_speaker_voices`.
+#[derive(Debug, Clone, Deserialize)]
+struct VoiceInfo {
+    id: String,
+    name: String,
+    language: String,
+    gender: String,
+}
+
+fn describe_native_voice(v: &tts::Voice) -> VoiceInfo {
+    VoiceInfo {
+        id: v.id(),
+        name: v.name(),
+        language: v.language(),
+        gender: v
+            .gender()
+            .map(|g| format!("{g:?}").to_lowercase())
+            .unwrap_or_else(|| "unknown".to_string()),
+    }
+}
+
 
 /// State file path (shared with service.rs).
 fn state_file() -> PathBuf {
@@ -58,6 +147,40 @@ fn write_state(state: &serde_json::Value) {
     }
 }
 
+fn truncate_preview(s: &str, max: usize) -> String {
+    if s.len() <= max {
+        s.to_string()
+    } else {
+        format!("{}...", &s[..max])
+    }
+}
+
+/// Cap on a single spoken chunk, in characters, so a run-on sentence with no
+/// punctuation still gets split for responsive, interruptible playback.
+const MAX_SPEECH_CHUNK_CHARS: usize = 200;
+
+/// Split `text` into sentence-sized chunks on `.`/`!`/`?` boundaries, capping
+/// each chunk at `max_len` chars.
+fn chunk_sentences(text: &str, max_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    for ch in text.chars() {
+        current.push(ch);
+        if matches!(ch, '.' | '!' | '?') || current.chars().count() >= max_len {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                chunks.push(trimmed.to_string());
+            }
+            current.clear();
+        }
+    }
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        chunks.push(trimmed.to_string());
+    }
+    chunks
+}
+
 fn update_state(updates: serde_json::Value) -> serde_json::Value {
     let mut state = read_state();
     if let (Some(state_obj), Some(updates_obj)) = (state.as_object_mut(), updates.as_object()) {
@@ -87,6 +210,19 @@ pub struct GetRecentRequest {
 pub struct GetDailyReportRequest {
     #[schemars(description = "Date to get report for - 'today' (default), 'list' (show available dates), or YYYY-MM-DD format")]
     pub date: Option<String>,
+    #[schemars(description = "Report format - 'markdown' (default), 'json', 'csv', or 'html'")]
+    pub format: Option<String>,
+}
+
+/// Parse a tool's optional `format` string into [`history::ReportFormat`],
+/// falling back to Markdown for anything unrecognized.
+fn parse_report_format(format: Option<&str>) -> history::ReportFormat {
+    match format {
+        Some("json") => history::ReportFormat::Json,
+        Some("csv") => history::ReportFormat::Csv,
+        Some("html") => history::ReportFormat::Html,
+        _ => history::ReportFormat::Markdown,
+    }
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -105,6 +241,8 @@ pub struct SetVoiceRequest {
 pub struct ReportRequest {
     #[schemars(description = "Date for report - 'today' (default), 'list', or YYYY-MM-DD")]
     pub date: Option<String>,
+    #[schemars(description = "Report format - 'markdown' (default), 'json', 'csv', or 'html'")]
+    pub format: Option<String>,
 }
 
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
@@ -113,6 +251,12 @@ pub struct TeachRequest {
     pub terms: String,
 }
 
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct FilterTermRequest {
+    #[schemars(description = "Words/phrases to redact from dictated text (comma-separated, e.g., 'John Smith, Acme Corp')")]
+    pub terms: String,
+}
+
 #[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
 pub struct AddCorrectionRequest {
     #[schemars(description = "The wrong/misrecognized text")]
@@ -121,26 +265,62 @@ pub struct AddCorrectionRequest {
     pub right: String,
 }
 
+#[derive(Debug, Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ImportCorrectionsRequest {
+    #[schemars(description = "Inline YAML or JSON object mapping wrong text to its correction")]
+    pub data: Option<String>,
+    #[schemars(description = "Path to a YAML or JSON file containing a wrong -> right mapping")]
+    pub path: Option<String>,
+    #[schemars(description = "Overwrite existing entries on conflict instead of preserving them (default: false)")]
+    pub overwrite: Option<bool>,
+}
+
 // --- MCP Server handler ---
 
 #[derive(Clone)]
 pub struct WhisperTyperMcp {
     tts_port: u16,
     http_client: reqwest::Client,
+    backend: TtsBackend,
+    native_tts: Option<Arc<NativeTts>>,
+    /// Cancellation token for the in-flight `code_speaker_speak` chunk queue.
+    /// Replaced with a fresh token at the start of every speak call so
+    /// `code_speaker_stop` only ever cancels the current readout.
+    speak_cancel: Arc<Mutex<CancellationToken>>,
     tool_router: ToolRouter<Self>,
 }
 
 #[tool_router]
 impl WhisperTyperMcp {
     pub fn new(tts_port: u16) -> Self {
+        Self::with_backend(tts_port, TtsBackend::Kokoro { port: tts_port })
+    }
+
+    /// Construct with an explicit TTS backend, e.g. `TtsBackend::Native` on
+    /// machines that don't run the Kokoro process.
+    pub fn with_backend(tts_port: u16, backend: TtsBackend) -> Self {
         let http_client = reqwest::Client::builder()
             .timeout(Duration::from_secs(5))
             .build()
             .expect("Failed to create HTTP client");
 
+        let native_tts = match backend {
+            TtsBackend::Native => match NativeTts::new() {
+                Ok(native) => Some(Arc::new(native)),
+                Err(e) => {
+                    warn!("Native TTS unavailable: {e}");
+                    None
+                }
+            },
+            TtsBackend::Kokoro { .. } => None,
+        };
+
         Self {
             tts_port,
             http_client,
+            backend,
+            native_tts,
+            speak_cancel: Arc::new(Mutex::new(CancellationToken::new())),
             tool_router: Self::tool_router(),
         }
     }
@@ -233,7 +413,7 @@ impl WhisperTyperMcp {
         Ok(CallToolResult::success(vec![Content::text(text)]))
     }
 
-    #[tool(description = "Get Markdown productivity report for a specific date.\n\nArgs:\n    date: Date to get report for - 'today' (default), 'list' (show available dates), or YYYY-MM-DD format")]
+    #[tool(description = "Get productivity report for a specific date.\n\nArgs:\n    date: Date to get report for - 'today' (default), 'list' (show available dates), or YYYY-MM-DD format\n    format: Report format - 'markdown' (default), 'json', 'csv', or 'html'")]
     async fn whisper_get_daily_report(
         &self,
         Parameters(req): Parameters<GetDailyReportRequest>,
@@ -254,43 +434,109 @@ impl WhisperTyperMcp {
             return Ok(CallToolResult::success(vec![Content::text(text)]));
         }
 
-        let report = history::generate_report(date);
+        let report = history::generate_report_as(date, parse_report_format(req.format.as_deref()));
         Ok(CallToolResult::success(vec![Content::text(report)]))
     }
 
     // --- Code Speaker TTS tools ---
 
-    #[tool(description = "Speak text aloud using Kokoro TTS.\n\nArgs:\n    text: The text to speak aloud")]
-    async fn code_speaker_speak(
-        &self,
-        Parameters(req): Parameters<SpeakRequest>,
-    ) -> Result<CallToolResult, McpError> {
+    /// Enumerate real voices from whichever backend is active. Guarded so a
+    /// backend that fails or returns garbage yields an empty list rather
+    /// than surfacing an error to the caller — the tools below treat an
+    /// empty list as "unknown, proceed anyway".
+    async fn fetch_voices(&self) -> Vec<VoiceInfo> {
+        if let Some(native) = &self.native_tts {
+            let native = native.clone();
+            return tokio::task::spawn_blocking(move || {
+                native.voices().iter().map(describe_native_voice).collect()
+            })
+            .await
+            .unwrap_or_default();
+        }
+
+        let url = format!("http://127.0.0.1:{}/voices", self.tts_port);
+        match self.http_client.get(&url).send().await {
+            Ok(resp) => resp.json::<Vec<VoiceInfo>>().await.unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Dispatch a single chunk of text to whichever TTS backend is active.
+    async fn speak_chunk(&self, text: &str) -> Result<(), String> {
+        if let Some(native) = &self.native_tts {
+            let native = native.clone();
+            let text = text.to_string();
+            return tokio::task::spawn_blocking(move || native.speak(&text))
+                .await
+                .unwrap_or_else(|e| Err(format!("Native TTS task panicked: {e}")));
+        }
+
         let url = format!("http://127.0.0.1:{}/speak", self.tts_port);
-        match self
-            .http_client
+        self.http_client
             .post(&url)
             .json(&json!({
-                "text": req.text,
+                "text": text,
                 "summarize": false,
                 "event_type": "manual"
             }))
             .send()
             .await
-        {
-            Ok(_) => {
-                let preview = if req.text.len() > 80 {
-                    format!("{}...", &req.text[..80])
-                } else {
-                    req.text
-                };
-                Ok(CallToolResult::success(vec![Content::text(format!(
-                    "Speaking: {preview}"
-                ))]))
+            .map(|_| ())
+            .map_err(|e| format!("{e}"))
+    }
+
+    #[tool(description = "Speak text aloud using Kokoro TTS, chunked by sentence so long passages report progress and can be halted with code_speaker_stop.\n\nArgs:\n    text: The text to speak aloud")]
+    async fn code_speaker_speak(
+        &self,
+        Parameters(req): Parameters<SpeakRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let chunks = chunk_sentences(&req.text, MAX_SPEECH_CHUNK_CHARS);
+        if chunks.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "Nothing to speak.",
+            )]));
+        }
+
+        let cancel = {
+            let mut guard = self.speak_cancel.lock().unwrap();
+            *guard = CancellationToken::new();
+            guard.clone()
+        };
+
+        let total_chars: usize = chunks.iter().map(|c| c.len()).sum();
+        let mut spoken_chars = 0;
+        let mut queued = 0;
+        for chunk in &chunks {
+            if cancel.is_cancelled() {
+                break;
             }
-            Err(e) => Ok(CallToolResult::success(vec![Content::text(format!(
-                "TTS error: {e}"
-            ))])),
+            if let Err(e) = self.speak_chunk(chunk).await {
+                return Ok(CallToolResult::success(vec![Content::text(format!(
+                    "TTS error: {e}"
+                ))]));
+            }
+            spoken_chars += chunk.len();
+            queued += 1;
         }
+
+        let preview = truncate_preview(&req.text, 80);
+        let msg = if queued < chunks.len() {
+            format!(
+                "Stopped after {queued}/{} chunk(s), {spoken_chars}/{total_chars} chars: {preview}",
+                chunks.len()
+            )
+        } else {
+            format!("Speaking {queued} chunk(s), {total_chars} chars: {preview}")
+        };
+        Ok(CallToolResult::success(vec![Content::text(msg)]))
+    }
+
+    #[tool(description = "Cancel the in-flight code_speaker_speak chunk queue so it dispatches no further chunks. Chunks already handed to the TTS backend are not recalled and keep playing.")]
+    async fn code_speaker_stop(&self) -> Result<CallToolResult, McpError> {
+        self.speak_cancel.lock().unwrap().cancel();
+        Ok(CallToolResult::success(vec![Content::text(
+            "Cancelled the in-flight speech queue.",
+        )]))
     }
 
     #[tool(description = "Set the TTS voice for code_speaker.\n\nArgs:\n    voice: Voice name (e.g., 'af_heart', 'bf_emma', 'am_adam')")]
@@ -298,6 +544,31 @@ impl WhisperTyperMcp {
         &self,
         Parameters(req): Parameters<SetVoiceRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let known = self.fetch_voices().await;
+        if !known.is_empty() && !known.iter().any(|v| v.id == req.voice) {
+            let mut by_distance: Vec<&VoiceInfo> = known.iter().collect();
+            by_distance.sort_by_key(|v| levenshtein(&v.id, &req.voice));
+            let suggestions: Vec<&str> =
+                by_distance.iter().take(3).map(|v| v.id.as_str()).collect();
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Unknown voice '{}'. Did you mean: {}?",
+                req.voice,
+                suggestions.join(", ")
+            ))]));
+        }
+
+        if let Some(native) = &self.native_tts {
+            let native = native.clone();
+            let voice = req.voice.clone();
+            let result = tokio::task::spawn_blocking(move || native.set_voice(&voice))
+                .await
+                .unwrap_or_else(|e| Err(format!("Native TTS task panicked: {e}")));
+            return Ok(CallToolResult::success(vec![Content::text(match result {
+                Ok(()) => format!("Voice set to: {}", req.voice),
+                Err(e) => format!("Error: {e}"),
+            })]));
+        }
+
         let url = format!("http://127.0.0.1:{}/set-voice", self.tts_port);
         match self
             .http_client
@@ -332,6 +603,13 @@ impl WhisperTyperMcp {
 
     #[tool(description = "Enable code_speaker TTS output.")]
     async fn code_speaker_enable(&self) -> Result<CallToolResult, McpError> {
+        if let Some(native) = &self.native_tts {
+            native.enabled.store(true, std::sync::atomic::Ordering::Relaxed);
+            return Ok(CallToolResult::success(vec![Content::text(
+                "Code Speaker TTS enabled",
+            )]));
+        }
+
         let url = format!("http://127.0.0.1:{}/enable", self.tts_port);
         match self.http_client.post(&url).send().await {
             Ok(_) => Ok(CallToolResult::success(vec![Content::text(
@@ -345,6 +623,13 @@ impl WhisperTyperMcp {
 
     #[tool(description = "Disable code_speaker TTS output.")]
     async fn code_speaker_disable(&self) -> Result<CallToolResult, McpError> {
+        if let Some(native) = &self.native_tts {
+            native.enabled.store(false, std::sync::atomic::Ordering::Relaxed);
+            return Ok(CallToolResult::success(vec![Content::text(
+                "Code Speaker TTS disabled",
+            )]));
+        }
+
         let url = format!("http://127.0.0.1:{}/disable", self.tts_port);
         match self.http_client.post(&url).send().await {
             Ok(_) => Ok(CallToolResult::success(vec![Content::text(
@@ -358,16 +643,25 @@ impl WhisperTyperMcp {
 
     #[tool(description = "List available TTS voices for code_speaker.")]
     async fn code_speaker_voices(&self) -> Result<CallToolResult, McpError> {
-        let text = "Available voice prefixes:\n\
-            - af_* (American female): af_heart, af_bella, af_nova, af_sarah\n\
-            - am_* (American male): am_adam, am_michael, am_echo\n\
-            - bf_* (British female): bf_emma, bf_alice, bf_lily\n\
-            - bm_* (British male): bm_george, bm_lewis\n\
-            Use code_speaker_set_voice to change.";
+        let voices = self.fetch_voices().await;
+        if voices.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No voices could be enumerated from the active TTS backend.",
+            )]));
+        }
+
+        let text = format!(
+            "Available voices:\n{}\nUse code_speaker_set_voice to change.",
+            voices
+                .iter()
+                .map(|v| format!("- {} ({}, {}): {}", v.id, v.language, v.gender, v.name))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
         Ok(CallToolResult::success(vec![Content::text(text)]))
     }
 
-    #[tool(description = "Get unified Voice I/O report (STT + TTS statistics).\n\nArgs:\n    date: Date for report - 'today' (default), 'list', or YYYY-MM-DD")]
+    #[tool(description = "Get the Voice I/O report of dictation (STT) statistics; TTS events are not included.\n\nArgs:\n    date: Date for report - 'today' (default), 'list', or YYYY-MM-DD\n    format: Report format - 'markdown' (default), 'json', 'csv', or 'html'")]
     async fn code_speaker_report(
         &self,
         Parameters(req): Parameters<ReportRequest>,
@@ -388,7 +682,7 @@ impl WhisperTyperMcp {
             return Ok(CallToolResult::success(vec![Content::text(text)]));
         }
 
-        let report = history::generate_report(date);
+        let report = history::generate_report_as(date, parse_report_format(req.format.as_deref()));
         Ok(CallToolResult::success(vec![Content::text(report)]))
     }
 
@@ -463,6 +757,72 @@ impl WhisperTyperMcp {
         Ok(CallToolResult::success(vec![Content::text(msg)]))
     }
 
+    #[tool(description = "Add words/phrases to redact from dictated text (names, profanity) after Ollama correction and before typing. Stored in .whisper/vocabulary_filter.txt.\n\nArgs:\n    terms: Comma-separated terms to redact (e.g., 'John Smith, Acme Corp')")]
+    async fn whisper_add_filter_term(
+        &self,
+        Parameters(req): Parameters<FilterTermRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let new_terms: Vec<String> = req
+            .terms
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        if new_terms.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No terms provided.",
+            )]));
+        }
+
+        let filter_path = PathBuf::from(".whisper/vocabulary_filter.txt");
+
+        let mut existing: HashSet<String> = if filter_path.exists() {
+            fs::read_to_string(&filter_path)
+                .unwrap_or_default()
+                .lines()
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        let mut added = Vec::new();
+        for term in &new_terms {
+            if existing.insert(term.clone()) {
+                added.push(term.as_str());
+            }
+        }
+
+        if let Some(parent) = filter_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let mut sorted: Vec<&String> = existing.iter().collect();
+        sorted.sort();
+        let contents = sorted.iter().map(|t| t.as_str()).collect::<Vec<_>>().join("\n");
+        if let Err(e) = fs::write(&filter_path, format!("{contents}\n")) {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Failed to write vocabulary filter file: {e}"
+            ))]));
+        }
+
+        // Signal service to reload
+        update_state(json!({ "filter_updated": true }));
+
+        let msg = if added.is_empty() {
+            format!("All {} terms already existed in the filter list.", new_terms.len())
+        } else {
+            format!(
+                "Added {} new filter term(s): {}. Total filter terms: {}.",
+                added.len(),
+                added.join(", "),
+                existing.len()
+            )
+        };
+        Ok(CallToolResult::success(vec![Content::text(msg)]))
+    }
+
     #[tool(description = "Add a speech correction mapping. When Whisper misrecognizes a word, this teaches Ollama the correct replacement. Stored in .whisper/corrections.yaml.\n\nArgs:\n    wrong: The misrecognized text\n    right: The correct replacement")]
     async fn whisper_add_correction(
         &self,
@@ -517,6 +877,127 @@ impl WhisperTyperMcp {
             corrections.len()
         ))]))
     }
+
+    #[tool(description = "Bulk-import speech correction mappings from an inline YAML/JSON map or a file, merging into .whisper/corrections.yaml in one pass.\n\nArgs:\n    data: Inline YAML or JSON object mapping wrong -> right (mutually exclusive with path)\n    path: Path to a YAML or JSON file with the same mapping\n    overwrite: Overwrite existing entries on conflict instead of preserving them (default: false)")]
+    async fn whisper_import_corrections(
+        &self,
+        Parameters(req): Parameters<ImportCorrectionsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let raw = match (&req.data, &req.path) {
+            (Some(data), _) => data.clone(),
+            (None, Some(path)) => match fs::read_to_string(path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    return Ok(CallToolResult::success(vec![Content::text(format!(
+                        "Failed to read {path}: {e}"
+                    ))]));
+                }
+            },
+            (None, None) => {
+                return Ok(CallToolResult::success(vec![Content::text(
+                    "Provide either 'data' (inline) or 'path' (file) to import from.",
+                )]));
+            }
+        };
+
+        let incoming: HashMap<String, String> = match serde_yml::from_str(&raw) {
+            Ok(map) => map,
+            Err(e) => {
+                return Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Failed to parse corrections as YAML/JSON: {e}"
+                ))]));
+            }
+        };
+
+        if incoming.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No corrections found in input.",
+            )]));
+        }
+
+        let overwrite = req.overwrite.unwrap_or(false);
+        let corrections_path = PathBuf::from(".whisper/corrections.yaml");
+        let mut corrections: HashMap<String, String> = if corrections_path.exists() {
+            let contents = fs::read_to_string(&corrections_path).unwrap_or_default();
+            serde_yml::from_str(&contents).unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+
+        let (mut added, mut updated, mut skipped) = (0, 0, 0);
+        for (wrong, right) in incoming {
+            let wrong = wrong.trim().to_string();
+            let right = right.trim().to_string();
+            if wrong.is_empty() || right.is_empty() {
+                skipped += 1;
+                continue;
+            }
+            match corrections.get(&wrong) {
+                Some(existing) if existing == &right => skipped += 1,
+                Some(_) if !overwrite => skipped += 1,
+                Some(_) => {
+                    corrections.insert(wrong, right);
+                    updated += 1;
+                }
+                None => {
+                    corrections.insert(wrong, right);
+                    added += 1;
+                }
+            }
+        }
+
+        if added > 0 || updated > 0 {
+            if let Some(parent) = corrections_path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            match serde_yml::to_string(&corrections) {
+                Ok(yaml) => {
+                    if let Err(e) = fs::write(&corrections_path, &yaml) {
+                        return Ok(CallToolResult::success(vec![Content::text(format!(
+                            "Failed to write corrections file: {e}"
+                        ))]));
+                    }
+                }
+                Err(e) => {
+                    return Ok(CallToolResult::success(vec![Content::text(format!(
+                        "Failed to serialize corrections: {e}"
+                    ))]));
+                }
+            }
+
+            // Signal service to reload once for the whole batch, not per entry.
+            update_state(json!({ "corrections_updated": true }));
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Imported corrections: {added} added, {updated} updated, {skipped} skipped. Total corrections: {}.",
+            corrections.len()
+        ))]))
+    }
+
+    #[tool(description = "Export the current speech correction mappings as YAML, for version control or backup.")]
+    async fn whisper_export_corrections(&self) -> Result<CallToolResult, McpError> {
+        let path = PathBuf::from(".whisper/corrections.yaml");
+        let contents = fs::read_to_string(&path).unwrap_or_default();
+        if contents.trim().is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No corrections recorded yet.",
+            )]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(contents)]))
+    }
+
+    #[tool(description = "Export the current vocabulary terms (one per line), for version control or backup.")]
+    async fn whisper_export_vocabulary(&self) -> Result<CallToolResult, McpError> {
+        let path = PathBuf::from(".whisper/vocabulary.txt");
+        let contents = fs::read_to_string(&path).unwrap_or_default();
+        if contents.trim().is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No vocabulary terms recorded yet.",
+            )]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(contents)]))
+    }
 }
 
 #[tool_handler]
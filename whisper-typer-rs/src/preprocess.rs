@@ -0,0 +1,128 @@
+//! Optional audio cleanup applied to a finished recording before the
+//! silence gate and transcription, inspired by WebRTC's capture pipeline:
+//! a high-pass filter to strip DC/rumble, noise-floor subtraction to
+//! suppress steady background hiss, and an AGC that tracks a running RMS
+//! and scales the signal toward a target level with attack/release
+//! smoothing. Off by default — see `PreprocessConfig::enabled`.
+
+use crate::config::PreprocessConfig;
+
+/// Frame size (in samples) the noise suppressor and AGC estimate levels
+/// over; small enough to react within a syllable, large enough to average
+/// out individual sample noise.
+const FRAME_LEN: usize = 256;
+
+/// Run the whole preprocessing chain over one finished utterance in
+/// place. No-op unless `config.enabled`.
+pub fn process(samples: &mut [f32], config: &PreprocessConfig, sample_rate: u32) {
+    if !config.enabled || samples.is_empty() {
+        return;
+    }
+    high_pass_filter(samples, config.high_pass_cutoff_hz, sample_rate);
+    suppress_noise(samples, config.noise_suppression_strength);
+    automatic_gain_control(
+        samples,
+        config.agc_target_rms,
+        config.agc_attack_ms,
+        config.agc_release_ms,
+        sample_rate,
+    );
+}
+
+/// One-pole high-pass filter (RC circuit approximation) that removes DC
+/// offset and sub-audible rumble, which would otherwise bias the noise
+/// floor estimate and AGC level below.
+fn high_pass_filter(samples: &mut [f32], cutoff_hz: f32, sample_rate: u32) {
+    if cutoff_hz <= 0.0 || samples.is_empty() {
+        return;
+    }
+    let dt = 1.0 / sample_rate as f32;
+    let rc = 1.0 / (2.0 * std::f32::consts::PI * cutoff_hz);
+    let alpha = rc / (rc + dt);
+
+    let mut prev_in = samples[0];
+    let mut prev_out = 0.0;
+    for sample in samples.iter_mut() {
+        let input = *sample;
+        let output = alpha * (prev_out + input - prev_in);
+        prev_in = input;
+        prev_out = output;
+        *sample = output;
+    }
+}
+
+/// Smoothed-noise-floor subtraction: the noise floor tracks the frame RMS
+/// down fast (quiet stretches update it immediately) and up slowly (an
+/// EMA, so loud speech doesn't drag the floor estimate up with it), then
+/// each frame is scaled down by a gain derived from how much of its
+/// energy looks like that floor. A cheap stand-in for full spectral
+/// subtraction that still suppresses steady hiss and fan/AC noise.
+fn suppress_noise(samples: &mut [f32], strength: f32) {
+    if strength <= 0.0 {
+        return;
+    }
+    const FLOOR_RISE_RATE: f32 = 0.05;
+    let mut noise_floor = 0.0f32;
+
+    for frame in samples.chunks_mut(FRAME_LEN) {
+        let frame_rms = rms(frame);
+        noise_floor = if noise_floor == 0.0 || frame_rms < noise_floor {
+            frame_rms
+        } else {
+            noise_floor + FLOOR_RISE_RATE * (frame_rms - noise_floor)
+        };
+
+        if frame_rms <= f32::EPSILON {
+            continue;
+        }
+        let gain = (1.0 - strength * (noise_floor / frame_rms)).clamp(0.0, 1.0);
+        for sample in frame.iter_mut() {
+            *sample *= gain;
+        }
+    }
+}
+
+/// Automatic gain control: tracks a running gain that drives each frame's
+/// RMS toward `target_rms`, moving at `attack_ms` when easing down from a
+/// loud frame and `release_ms` when recovering from a quiet one — the
+/// usual asymmetric attack/release split so loud transients get clamped
+/// quickly but quiet stretches don't get amplified the instant they end.
+fn automatic_gain_control(
+    samples: &mut [f32],
+    target_rms: f32,
+    attack_ms: f64,
+    release_ms: f64,
+    sample_rate: u32,
+) {
+    if target_rms <= 0.0 {
+        return;
+    }
+    let frame_seconds = FRAME_LEN as f64 / sample_rate as f64;
+    let attack_coeff = (-frame_seconds / (attack_ms / 1000.0).max(0.001)).exp() as f32;
+    let release_coeff = (-frame_seconds / (release_ms / 1000.0).max(0.001)).exp() as f32;
+
+    let mut gain = 1.0f32;
+    for frame in samples.chunks_mut(FRAME_LEN) {
+        let frame_rms = rms(frame);
+        let desired_gain = if frame_rms > f32::EPSILON {
+            (target_rms / frame_rms).clamp(0.1, 4.0)
+        } else {
+            gain
+        };
+
+        let coeff = if desired_gain < gain { attack_coeff } else { release_coeff };
+        gain = coeff * gain + (1.0 - coeff) * desired_gain;
+
+        for sample in frame.iter_mut() {
+            *sample = (*sample * gain).clamp(-1.0, 1.0);
+        }
+    }
+}
+
+fn rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    let sum_sq: f32 = frame.iter().map(|s| s * s).sum();
+    (sum_sq / frame.len() as f32).sqrt()
+}
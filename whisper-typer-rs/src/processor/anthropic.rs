@@ -0,0 +1,101 @@
+//! Anthropic messages-API correction backend.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::config::OllamaConfig;
+
+use super::{build_prompt, RateLimiter, TextProcessor};
+
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+pub struct AnthropicProcessor {
+    config: OllamaConfig,
+    client: Client,
+    limiter: RateLimiter,
+}
+
+impl AnthropicProcessor {
+    pub fn new(config: OllamaConfig) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+        let limiter = RateLimiter::new(config.max_requests_per_second);
+
+        Self { config, client, limiter }
+    }
+}
+
+#[async_trait]
+impl TextProcessor for AnthropicProcessor {
+    async fn process(&self, text: &str, corrections: Option<&HashMap<String, String>>) -> String {
+        if !self.config.enabled || text.trim().is_empty() {
+            return text.to_string();
+        }
+
+        self.limiter.wait_turn().await;
+        let prompt = build_prompt(text, corrections);
+        debug!("Sending to Anthropic model '{}': {}", self.config.model, text);
+
+        let body = json!({
+            "model": self.config.model,
+            "max_tokens": self.config.num_predict,
+            "temperature": self.config.temperature,
+            "messages": [{"role": "user", "content": prompt}]
+        });
+
+        let url = format!("{}/v1/messages", self.config.host);
+
+        match self
+            .client
+            .post(&url)
+            .header("x-api-key", &self.config.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&body)
+            .send()
+            .await
+        {
+            Ok(resp) => {
+                if !resp.status().is_success() {
+                    warn!("Anthropic backend returned status {}", resp.status());
+                    return text.to_string();
+                }
+                match resp.json::<serde_json::Value>().await {
+                    Ok(data) => {
+                        let result = data["content"][0]["text"]
+                            .as_str()
+                            .unwrap_or("")
+                            .trim()
+                            .to_string();
+                        if result.is_empty() {
+                            warn!("Anthropic backend returned empty response, using original text");
+                            text.to_string()
+                        } else {
+                            debug!("Anthropic backend output: '{result}'");
+                            result
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse Anthropic response: {e}");
+                        text.to_string()
+                    }
+                }
+            }
+            Err(e) => {
+                if e.is_connect() {
+                    warn!("Cannot connect to Anthropic backend at {}", self.config.host);
+                } else if e.is_timeout() {
+                    warn!("Anthropic backend request timed out");
+                } else {
+                    warn!("Anthropic backend request failed: {e}");
+                }
+                text.to_string()
+            }
+        }
+    }
+}
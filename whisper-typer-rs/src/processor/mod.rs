@@ -0,0 +1,120 @@
+//! Text-correction backends for grammar/punctuation cleanup of transcribed
+//! speech.
+//!
+//! All backends implement [`TextProcessor`] and share the same prompt
+//! template and per-project corrections injection; they differ only in how
+//! they build the request body and parse the response. Selected at startup
+//! by `OllamaConfig::backend`. Components:
+//! - `ollama`: local Ollama `/api/generate` (default, no API key required)
+//! - `openai`: OpenAI-compatible chat-completions endpoint
+//! - `anthropic`: Anthropic messages endpoint
+
+mod anthropic;
+mod ollama;
+mod openai;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::config::{CorrectionBackend, OllamaConfig};
+
+pub use ollama::OllamaProcessor;
+
+const PROMPT_TEMPLATE: &str = r#"Fix this speech transcription. Correct:
+- Grammar and punctuation
+- Misspelled names
+- Technical terms
+- Every sentence must end with a full stop or question mark
+
+Output ONLY the corrected text, nothing else.
+
+Text: {text}
+
+Corrected:"#;
+
+/// A pluggable grammar/punctuation correction backend.
+///
+/// Implementations must fall back to returning `text` unchanged on any
+/// connection, HTTP, or parse error rather than propagating it — a broken
+/// correction backend should never block dictation output.
+#[async_trait]
+pub trait TextProcessor: Send + Sync {
+    /// Process `text`, optionally biased by known per-project `corrections`.
+    async fn process(&self, text: &str, corrections: Option<&HashMap<String, String>>) -> String;
+}
+
+/// Build the shared prompt: the base template with `corrections` injected
+/// as a substitutions section before the `Text:` line, if any are given.
+fn build_prompt(text: &str, corrections: Option<&HashMap<String, String>>) -> String {
+    let mut prompt = PROMPT_TEMPLATE.replace("{text}", text);
+
+    if let Some(corrections) = corrections {
+        if !corrections.is_empty() {
+            let mut section = String::from("\n\nKnown corrections (apply these substitutions):\n");
+            for (wrong, right) in corrections {
+                section.push_str(&format!("- \"{wrong}\" → \"{right}\"\n"));
+            }
+            // Insert before the "Text:" line
+            prompt = prompt.replacen("\nText:", &format!("{section}\nText:"), 1);
+        }
+    }
+
+    prompt
+}
+
+/// Async leaky-bucket limiter shared across calls on one processor
+/// instance, so a burst of short transcriptions doesn't fire an unbounded
+/// stream of requests at the configured backend.
+pub struct RateLimiter {
+    min_interval: Duration,
+    last_sent: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests_per_second: f32) -> Self {
+        let min_interval = if max_requests_per_second > 0.0 {
+            Duration::from_secs_f32(1.0 / max_requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            min_interval,
+            last_sent: Mutex::new(None),
+        }
+    }
+
+    /// Wait until at least `min_interval` has elapsed since the last
+    /// dispatched request, then record this dispatch's timestamp.
+    pub async fn wait_turn(&self) {
+        if self.min_interval.is_zero() {
+            return;
+        }
+
+        let wait = {
+            let mut last_sent = self.last_sent.lock().unwrap();
+            let now = Instant::now();
+            let wait = last_sent
+                .map(|t| self.min_interval.saturating_sub(now.duration_since(t)))
+                .unwrap_or(Duration::ZERO);
+            *last_sent = Some(now + wait);
+            wait
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Construct the configured text-correction backend.
+pub fn create(config: &OllamaConfig) -> Box<dyn TextProcessor> {
+    match config.backend {
+        CorrectionBackend::Ollama => Box::new(ollama::OllamaProcessor::new(config.clone())),
+        CorrectionBackend::OpenAi => Box::new(openai::OpenAiProcessor::new(config.clone())),
+        CorrectionBackend::Anthropic => Box::new(anthropic::AnthropicProcessor::new(config.clone())),
+    }
+}
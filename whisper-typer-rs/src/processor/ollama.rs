@@ -0,0 +1,309 @@
+//! Local Ollama `/api/generate` correction backend.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use tracing::{debug, info, warn};
+
+use crate::config::OllamaConfig;
+
+use super::{build_prompt, RateLimiter, TextProcessor};
+
+pub struct OllamaProcessor {
+    config: OllamaConfig,
+    client: Client,
+    limiter: RateLimiter,
+}
+
+impl OllamaProcessor {
+    pub fn new(config: OllamaConfig) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+        let limiter = RateLimiter::new(config.max_requests_per_second);
+
+        Self { config, client, limiter }
+    }
+}
+
+#[async_trait]
+impl TextProcessor for OllamaProcessor {
+    /// Process text through Ollama for grammar correction.
+    /// Returns the original text if Ollama is disabled or unavailable.
+    /// If `corrections` is provided, known substitutions are inserted into the prompt before the `Text:` line.
+    async fn process(&self, text: &str, corrections: Option<&HashMap<String, String>>) -> String {
+        if !self.config.enabled || text.trim().is_empty() {
+            return text.to_string();
+        }
+
+        self.limiter.wait_turn().await;
+        let prompt = build_prompt(text, corrections);
+        debug!("Sending to Ollama model '{}': {}", self.config.model, text);
+
+        let body = json!({
+            "model": self.config.model,
+            "prompt": prompt,
+            "stream": false,
+            "options": {
+                "temperature": self.config.temperature,
+                "num_predict": self.config.num_predict,
+                "num_ctx": self.config.num_ctx
+            }
+        });
+
+        let url = format!("{}/api/generate", self.config.host);
+
+        match self.client.post(&url).json(&body).send().await {
+            Ok(resp) => {
+                if !resp.status().is_success() {
+                    warn!("Ollama returned status {}", resp.status());
+                    return text.to_string();
+                }
+                match resp.json::<serde_json::Value>().await {
+                    Ok(data) => {
+                        let result = data["response"]
+                            .as_str()
+                            .unwrap_or("")
+                            .trim()
+                            .to_string();
+                        if result.is_empty() {
+                            warn!("Ollama returned empty response, using original text");
+                            text.to_string()
+                        } else {
+                            debug!("Ollama output: '{result}'");
+                            result
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse Ollama response: {e}");
+                        text.to_string()
+                    }
+                }
+            }
+            Err(e) => {
+                if e.is_connect() {
+                    warn!("Cannot connect to Ollama at {}", self.config.host);
+                } else if e.is_timeout() {
+                    warn!("Ollama request timed out");
+                } else {
+                    warn!("Ollama request failed: {e}");
+                }
+                text.to_string()
+            }
+        }
+    }
+}
+
+impl OllamaProcessor {
+    /// Confirm the Ollama server is reachable and `config.model` is
+    /// installed, by hitting `/api/tags`. Replaces discovering either
+    /// problem via a failed/stalled `process()` call on first use.
+    ///
+    /// If the model is missing and `config.auto_pull` is set, POSTs to
+    /// `/api/pull` and streams the download progress to the log instead of
+    /// erroring out.
+    pub async fn ensure_model_ready(&self) -> Result<(), String> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let tags_url = format!("{}/api/tags", self.config.host);
+        let resp = self
+            .client
+            .get(&tags_url)
+            .send()
+            .await
+            .map_err(|e| format!("Cannot reach Ollama at {}: {e}", self.config.host))?;
+
+        if !resp.status().is_success() {
+            return Err(format!("Ollama /api/tags returned status {}", resp.status()));
+        }
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Ollama /api/tags response: {e}"))?;
+
+        let installed = data["models"]
+            .as_array()
+            .map(|models| {
+                models
+                    .iter()
+                    .any(|m| m["name"].as_str() == Some(self.config.model.as_str()))
+            })
+            .unwrap_or(false);
+
+        if installed {
+            return Ok(());
+        }
+
+        if !self.config.auto_pull {
+            return Err(format!(
+                "Model '{}' is not installed in Ollama (auto_pull is disabled)",
+                self.config.model
+            ));
+        }
+
+        info!("Model '{}' not installed, pulling...", self.config.model);
+        self.pull_model().await
+    }
+
+    /// POST `/api/pull` and stream the download progress to the log.
+    async fn pull_model(&self) -> Result<(), String> {
+        let pull_url = format!("{}/api/pull", self.config.host);
+        let body = json!({ "name": self.config.model, "stream": true });
+
+        let mut resp = self
+            .client
+            .post(&pull_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to start pulling '{}': {e}", self.config.model))?;
+
+        if !resp.status().is_success() {
+            return Err(format!(
+                "Ollama /api/pull returned status {} for '{}'",
+                resp.status(),
+                self.config.model
+            ));
+        }
+
+        let mut line_buf = String::new();
+        loop {
+            let chunk = resp
+                .chunk()
+                .await
+                .map_err(|e| format!("Pull stream read failed: {e}"))?;
+            let Some(chunk) = chunk else { break };
+            line_buf.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(newline) = line_buf.find('\n') {
+                let line = line_buf[..newline].trim().to_string();
+                line_buf.drain(..=newline);
+                if line.is_empty() {
+                    continue;
+                }
+                if let Ok(data) = serde_json::from_str::<serde_json::Value>(&line) {
+                    if let Some(status) = data["status"].as_str() {
+                        info!("Pulling '{}': {status}", self.config.model);
+                    }
+                    if let Some(err) = data["error"].as_str() {
+                        return Err(format!("Ollama pull failed for '{}': {err}", self.config.model));
+                    }
+                }
+            }
+        }
+
+        info!("Model '{}' pulled successfully", self.config.model);
+        Ok(())
+    }
+
+    /// Like [`TextProcessor::process`], but streams the response as Ollama
+    /// produces it: each newline-delimited JSON chunk's `"response"` field
+    /// is appended to the accumulated result and passed to `on_chunk`, so a
+    /// caller can act on partial output (e.g. start TTS on the first
+    /// completed sentence) before the full correction finishes.
+    ///
+    /// Falls back to whatever text accumulated so far (or the original
+    /// text, if nothing arrived) on a mid-stream parse or connection error.
+    pub async fn process_streaming(
+        &self,
+        text: &str,
+        corrections: Option<&HashMap<String, String>>,
+        mut on_chunk: impl FnMut(&str),
+    ) -> String {
+        if !self.config.enabled || text.trim().is_empty() {
+            return text.to_string();
+        }
+
+        self.limiter.wait_turn().await;
+        let prompt = build_prompt(text, corrections);
+        debug!("Streaming to Ollama model '{}': {}", self.config.model, text);
+
+        let body = json!({
+            "model": self.config.model,
+            "prompt": prompt,
+            "stream": true,
+            "options": {
+                "temperature": self.config.temperature,
+                "num_predict": self.config.num_predict,
+                "num_ctx": self.config.num_ctx
+            }
+        });
+
+        let url = format!("{}/api/generate", self.config.host);
+
+        let mut resp = match self.client.post(&url).json(&body).send().await {
+            Ok(resp) if resp.status().is_success() => resp,
+            Ok(resp) => {
+                warn!("Ollama returned status {}", resp.status());
+                return text.to_string();
+            }
+            Err(e) => {
+                if e.is_connect() {
+                    warn!("Cannot connect to Ollama at {}", self.config.host);
+                } else if e.is_timeout() {
+                    warn!("Ollama request timed out");
+                } else {
+                    warn!("Ollama request failed: {e}");
+                }
+                return text.to_string();
+            }
+        };
+
+        let mut accumulated = String::new();
+        let mut line_buf = String::new();
+
+        loop {
+            let chunk = match resp.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => break,
+                Err(e) => {
+                    warn!("Ollama stream read failed: {e}, using partial result");
+                    break;
+                }
+            };
+
+            line_buf.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(newline) = line_buf.find('\n') {
+                let line = line_buf[..newline].trim().to_string();
+                line_buf.drain(..=newline);
+                if line.is_empty() {
+                    continue;
+                }
+
+                match serde_json::from_str::<serde_json::Value>(&line) {
+                    Ok(data) => {
+                        if let Some(piece) = data["response"].as_str() {
+                            if !piece.is_empty() {
+                                accumulated.push_str(piece);
+                                on_chunk(piece);
+                            }
+                        }
+                        if data["done"].as_bool().unwrap_or(false) {
+                            let result = accumulated.trim().to_string();
+                            return if result.is_empty() { text.to_string() } else { result };
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse Ollama stream chunk: {e}, using partial result");
+                        let result = accumulated.trim().to_string();
+                        return if result.is_empty() { text.to_string() } else { result };
+                    }
+                }
+            }
+        }
+
+        let result = accumulated.trim().to_string();
+        if result.is_empty() {
+            text.to_string()
+        } else {
+            result
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! OpenAI-compatible chat-completions correction backend.
+//!
+//! Targets `{host}/chat/completions` with a bearer-token `Authorization`
+//! header, so it also works against Mistral's and other OpenAI-compatible
+//! hosted APIs by pointing `host`/`model` at them.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::config::OllamaConfig;
+
+use super::{build_prompt, RateLimiter, TextProcessor};
+
+pub struct OpenAiProcessor {
+    config: OllamaConfig,
+    client: Client,
+    limiter: RateLimiter,
+}
+
+impl OpenAiProcessor {
+    pub fn new(config: OllamaConfig) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+        let limiter = RateLimiter::new(config.max_requests_per_second);
+
+        Self { config, client, limiter }
+    }
+}
+
+#[async_trait]
+impl TextProcessor for OpenAiProcessor {
+    async fn process(&self, text: &str, corrections: Option<&HashMap<String, String>>) -> String {
+        if !self.config.enabled || text.trim().is_empty() {
+            return text.to_string();
+        }
+
+        self.limiter.wait_turn().await;
+        let prompt = build_prompt(text, corrections);
+        debug!("Sending to OpenAI-compatible model '{}': {}", self.config.model, text);
+
+        let body = json!({
+            "model": self.config.model,
+            "messages": [{"role": "user", "content": prompt}],
+            "temperature": self.config.temperature,
+            "max_tokens": self.config.num_predict
+        });
+
+        let url = format!("{}/chat/completions", self.config.host);
+
+        let mut request = self.client.post(&url).json(&body);
+        if !self.config.api_key.is_empty() {
+            request = request.bearer_auth(&self.config.api_key);
+        }
+
+        match request.send().await {
+            Ok(resp) => {
+                if !resp.status().is_success() {
+                    warn!("OpenAI-compatible backend returned status {}", resp.status());
+                    return text.to_string();
+                }
+                match resp.json::<serde_json::Value>().await {
+                    Ok(data) => {
+                        let result = data["choices"][0]["message"]["content"]
+                            .as_str()
+                            .unwrap_or("")
+                            .trim()
+                            .to_string();
+                        if result.is_empty() {
+                            warn!("OpenAI-compatible backend returned empty response, using original text");
+                            text.to_string()
+                        } else {
+                            debug!("OpenAI-compatible backend output: '{result}'");
+                            result
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse OpenAI-compatible response: {e}");
+                        text.to_string()
+                    }
+                }
+            }
+            Err(e) => {
+                if e.is_connect() {
+                    warn!("Cannot connect to OpenAI-compatible backend at {}", self.config.host);
+                } else if e.is_timeout() {
+                    warn!("OpenAI-compatible backend request timed out");
+                } else {
+                    warn!("OpenAI-compatible backend request failed: {e}");
+                }
+                text.to_string()
+            }
+        }
+    }
+}
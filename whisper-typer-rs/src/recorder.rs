@@ -2,14 +2,47 @@
 //!
 //! Keeps the audio stream open for low-latency recording start.
 //! Captures 16kHz mono f32 audio suitable for Whisper.
+//!
+//! End-of-utterance auto-stop is driven by a pluggable `vad::SilenceDetector`
+//! (WebRTC VAD by default, or an FFT-based in-band energy ratio — see
+//! `SilenceConfig::mode`) rather than a raw RMS threshold, so quiet speech
+//! doesn't trigger a false stop and noisy input doesn't delay one.
+//! `AudioRecorder::is_silent`'s RMS check still runs as a final gate over
+//! the whole captured utterance.
+//!
+//! Following ALVR's approach of retrying its audio thread on error, a
+//! dropped or errored stream (device unplugged, format renegotiation
+//! failure, ...) is rebuilt with exponential backoff rather than leaving
+//! the recorder silently dead — see `AudioRecorder::poll_recovery`.
+//!
+//! Live-caption streaming (see `AudioRecorder::snapshot_from`) re-reads the
+//! growing per-utterance buffer from an offset rather than draining a
+//! separate chunk queue, so the service can poll it at its own pace
+//! without the recorder tracking a second consumer cursor.
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::{SampleRate, Stream, StreamConfig};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 use crate::config::{AudioConfig, RecordingConfig, SilenceConfig};
+use crate::vad::SilenceDetector;
+
+/// Surfaced by `AudioRecorder::poll_recovery` so the service can log or
+/// notify when the input stream drops out and comes back, mirroring the
+/// `HotkeyStatus` events the keyboard side reports on hotplug.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioStatus {
+    /// The stream reported an unrecoverable error and is being rebuilt.
+    Degraded(String),
+    /// A previously degraded stream has been rebuilt and is running again.
+    Recovered,
+}
+
+/// Ceiling on the exponential backoff between stream rebuild attempts,
+/// so a persistently missing device doesn't get hammered with retries.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
 
 /// Audio recorder with always-open stream for low-latency start.
 pub struct AudioRecorder {
@@ -21,7 +54,13 @@ pub struct AudioRecorder {
     /// Shared state between the audio callback thread and the main thread.
     shared: Arc<SharedState>,
     /// The cpal stream handle. Kept alive to maintain the always-open stream.
+    /// `None` when no stream has been opened yet, or while a degraded
+    /// stream is waiting for its next rebuild attempt (see `poll_recovery`).
     _stream: Option<Stream>,
+    /// Rebuild attempts made since the stream last degraded, for backoff.
+    retry_attempt: u32,
+    /// Earliest time `poll_recovery` should attempt the next rebuild.
+    next_retry_at: Option<Instant>,
 }
 
 struct SharedState {
@@ -32,10 +71,33 @@ struct RecorderInner {
     is_recording: bool,
     buffer: Vec<f32>,
     max_samples: usize,
-    // Silence detection state
-    silence_start: Option<Instant>,
     recording_start: Option<Instant>,
     should_auto_stop: bool,
+    // VAD-driven auto-stop state
+    detector: SilenceDetector,
+    /// Leftover samples not yet forming a full classifier frame (see
+    /// `SilenceDetector::frame_len`).
+    detector_frame_buf: Vec<f32>,
+    /// At least one voiced frame has been seen since `start()`; auto-stop
+    /// only arms once speech has actually begun.
+    voiced_seen: bool,
+    /// Consecutive unvoiced frames since the last voiced one.
+    consecutive_unvoiced_frames: u32,
+    /// Unvoiced frames required (after `voiced_seen`) to auto-stop —
+    /// derived from `SilenceConfig::duration` / the detector's frame size.
+    hangover_frames: u32,
+    // Linear resampler state (see `resample_to_target`)
+    /// Fractional source-domain position of the next output sample,
+    /// relative to the start of `resample_carry`.
+    resample_pos: f64,
+    /// Trailing input samples not yet consumed by the resampler, carried
+    /// over so block boundaries don't click.
+    resample_carry: Vec<f32>,
+    /// Set by the cpal error callback (which runs on the audio thread and
+    /// must return quickly) when the stream reports an error. Drained by
+    /// `AudioRecorder::poll_recovery` on the main task, which owns
+    /// rebuilding the stream.
+    stream_error: Option<String>,
 }
 
 impl AudioRecorder {
@@ -47,14 +109,26 @@ impl AudioRecorder {
         let max_samples =
             (recording_config.max_duration * audio_config.sample_rate as f64) as usize;
 
+        let detector = SilenceDetector::new(audio_config.sample_rate, &silence_config);
+        let detector_frame_ms = (detector.frame_len() * 1000 / audio_config.sample_rate.max(1) as usize) as u32;
+        let hangover_frames =
+            ((silence_config.duration * 1000.0) as u32 / detector_frame_ms.max(1)).max(1);
+
         let shared = Arc::new(SharedState {
             inner: Mutex::new(RecorderInner {
                 is_recording: false,
                 buffer: Vec::with_capacity(max_samples),
                 max_samples,
-                silence_start: None,
                 recording_start: None,
                 should_auto_stop: false,
+                detector_frame_buf: Vec::with_capacity(detector.frame_len()),
+                detector,
+                voiced_seen: false,
+                consecutive_unvoiced_frames: 0,
+                hangover_frames,
+                resample_pos: 0.0,
+                resample_carry: Vec::new(),
+                stream_error: None,
             }),
         });
 
@@ -64,6 +138,8 @@ impl AudioRecorder {
             silence_config,
             shared,
             _stream: None,
+            retry_attempt: 0,
+            next_retry_at: None,
         }
     }
 
@@ -72,26 +148,77 @@ impl AudioRecorder {
         if self._stream.is_some() {
             return Ok(());
         }
+        self.build_stream()
+    }
+
+    /// Periodically drive automatic stream recovery: if the cpal error
+    /// callback flagged a failure, tear down the stream and start backing
+    /// off; once backed off long enough, attempt a rebuild. Call this from
+    /// a polling loop (e.g. `DictationService::run`'s select loop) rather than the
+    /// error callback itself, which runs on the audio thread and must
+    /// return immediately.
+    pub fn poll_recovery(&mut self) -> Option<AudioStatus> {
+        let failed = self.shared.inner.lock().unwrap().stream_error.take();
+        if let Some(err) = failed {
+            self._stream = None;
+            self.retry_attempt = 0;
+            self.next_retry_at = Some(Instant::now());
+            return Some(AudioStatus::Degraded(err));
+        }
+
+        if self._stream.is_some() {
+            return None;
+        }
+        let due = self.next_retry_at?;
+        if Instant::now() < due {
+            return None;
+        }
 
+        match self.build_stream() {
+            Ok(()) => {
+                info!("Audio stream recovered after {} attempt(s)", self.retry_attempt + 1);
+                self.retry_attempt = 0;
+                self.next_retry_at = None;
+                Some(AudioStatus::Recovered)
+            }
+            Err(e) => {
+                self.retry_attempt += 1;
+                let delay = Duration::from_millis(500)
+                    .saturating_mul(1u32 << self.retry_attempt.min(6))
+                    .min(MAX_RETRY_BACKOFF);
+                warn!("Stream rebuild attempt {} failed: {e}, retrying in {delay:?}", self.retry_attempt);
+                self.next_retry_at = Some(Instant::now() + delay);
+                None
+            }
+        }
+    }
+
+    /// Build (or rebuild) the cpal input stream: select the device,
+    /// negotiate a supported format, and wire up the capture callback.
+    fn build_stream(&mut self) -> Result<(), String> {
         let host = cpal::default_host();
-        let device = host
-            .default_input_device()
-            .ok_or("No input audio device available")?;
+        let device = select_input_device(&host, &self.config.device)?;
 
         info!(
             "Using audio device: {}",
             device.name().unwrap_or("unknown".into())
         );
 
-        let stream_config = StreamConfig {
-            channels: self.config.channels,
-            sample_rate: SampleRate(self.config.sample_rate),
-            buffer_size: cpal::BufferSize::Fixed(self.config.chunk_size),
-        };
+        // Query what the device actually supports rather than forcing our
+        // target rate/channels — many devices reject an exact 16kHz mono
+        // request outright. We downmix + resample to the target in the
+        // callback below instead.
+        let negotiated = negotiate_input_config(&device, self.config.sample_rate, self.config.channels)?;
+        let in_sample_rate = negotiated.sample_rate().0;
+        let in_channels = negotiated.channels() as usize;
+        let target_sample_rate = self.config.sample_rate;
+        info!(
+            "Input stream format: {in_sample_rate}Hz, {in_channels} channel(s) -> resampling to {target_sample_rate}Hz mono"
+        );
+        let stream_config: StreamConfig = negotiated.config();
 
         let shared = Arc::clone(&self.shared);
-        let silence_threshold = self.silence_config.threshold;
-        let silence_duration = self.silence_config.duration;
+        let shared_err = Arc::clone(&self.shared);
         let min_speech_duration = self.silence_config.min_speech_duration;
         let max_recording_duration = self.silence_config.max_recording_duration;
 
@@ -105,10 +232,18 @@ impl AudioRecorder {
                         return;
                     }
 
+                    let mono = downmix_to_mono(data, in_channels);
+                    let resampled = resample_to_target(
+                        &mut inner,
+                        &mono,
+                        in_sample_rate as f64,
+                        target_sample_rate as f64,
+                    );
+
                     // Append samples to buffer
                     let remaining = inner.max_samples.saturating_sub(inner.buffer.len());
-                    let to_copy = data.len().min(remaining);
-                    inner.buffer.extend_from_slice(&data[..to_copy]);
+                    let to_copy = resampled.len().min(remaining);
+                    inner.buffer.extend_from_slice(&resampled[..to_copy]);
 
                     if inner.buffer.len() >= inner.max_samples {
                         warn!("Max recording duration reached");
@@ -117,40 +252,53 @@ impl AudioRecorder {
                         return;
                     }
 
-                    // Silence detection
-                    if let Some(rec_start) = inner.recording_start {
-                        let elapsed = rec_start.elapsed().as_secs_f64();
+                    let Some(rec_start) = inner.recording_start else {
+                        return;
+                    };
+                    let elapsed = rec_start.elapsed().as_secs_f64();
 
-                        // Check max recording duration
-                        if elapsed >= max_recording_duration {
-                            info!("Max recording duration reached ({max_recording_duration}s)");
-                            inner.should_auto_stop = true;
-                            return;
-                        }
+                    // Check max recording duration
+                    if elapsed >= max_recording_duration {
+                        info!("Max recording duration reached ({max_recording_duration}s)");
+                        inner.should_auto_stop = true;
+                        return;
+                    }
 
-                        // Don't check silence until minimum speech duration
-                        if elapsed < min_speech_duration {
-                            return;
-                        }
+                    // Don't run the VAD until minimum speech duration
+                    if elapsed < min_speech_duration {
+                        return;
+                    }
 
-                        // RMS energy
-                        let rms = rms_energy(data);
-                        let is_silent = rms < silence_threshold;
+                    // Voice-activity gate: classify fixed-size frames of the
+                    // resampled (target-rate) audio, carrying any remainder
+                    // over to the next audio callback.
+                    inner.detector_frame_buf.extend_from_slice(&resampled[..to_copy]);
 
-                        if is_silent {
-                            let silence_start =
-                                inner.silence_start.get_or_insert_with(Instant::now);
-                            if silence_start.elapsed().as_secs_f64() >= silence_duration {
-                                debug!("Silence detected for {silence_duration}s — auto-stopping");
+                    let frame_len = inner.detector.frame_len();
+                    while inner.detector_frame_buf.len() >= frame_len {
+                        let frame: Vec<f32> = inner.detector_frame_buf.drain(..frame_len).collect();
+                        let voiced = inner.detector.is_voice(&frame);
+
+                        if voiced {
+                            inner.voiced_seen = true;
+                            inner.consecutive_unvoiced_frames = 0;
+                        } else {
+                            inner.consecutive_unvoiced_frames += 1;
+                            if inner.voiced_seen
+                                && inner.consecutive_unvoiced_frames >= inner.hangover_frames
+                            {
+                                debug!(
+                                    "VAD: {} consecutive unvoiced frames — auto-stopping",
+                                    inner.consecutive_unvoiced_frames
+                                );
                                 inner.should_auto_stop = true;
                             }
-                        } else {
-                            inner.silence_start = None;
                         }
                     }
                 },
                 move |err| {
                     warn!("Audio stream error: {err}");
+                    shared_err.inner.lock().unwrap().stream_error = Some(err.to_string());
                 },
                 None, // timeout
             )
@@ -168,9 +316,13 @@ impl AudioRecorder {
         let mut inner = self.shared.inner.lock().unwrap();
         inner.buffer.clear();
         inner.is_recording = true;
-        inner.silence_start = None;
         inner.recording_start = Some(Instant::now());
         inner.should_auto_stop = false;
+        inner.detector_frame_buf.clear();
+        inner.voiced_seen = false;
+        inner.consecutive_unvoiced_frames = 0;
+        inner.resample_pos = 0.0;
+        inner.resample_carry.clear();
         info!("Recording started");
     }
 
@@ -189,6 +341,16 @@ impl AudioRecorder {
         self.shared.inner.lock().unwrap().should_auto_stop
     }
 
+    /// Copy out samples appended since `from_offset` without interrupting
+    /// the in-progress recording, for the live-captioning streaming path.
+    pub fn snapshot_from(&self, from_offset: usize) -> Vec<f32> {
+        let inner = self.shared.inner.lock().unwrap();
+        if from_offset >= inner.buffer.len() {
+            return Vec::new();
+        }
+        inner.buffer[from_offset..].to_vec()
+    }
+
     /// Check if currently recording.
     #[allow(dead_code)]
     pub fn is_recording(&self) -> bool {
@@ -206,6 +368,137 @@ impl AudioRecorder {
     pub fn sample_rate(&self) -> u32 {
         self.config.sample_rate
     }
+
+    /// List the names of available input devices on the default host, for
+    /// `--list-devices` and for picking a value for `config.audio.device`.
+    pub fn list_input_devices() -> Vec<String> {
+        let host = cpal::default_host();
+        match host.input_devices() {
+            Ok(devices) => devices.filter_map(|d| d.name().ok()).collect(),
+            Err(e) => {
+                warn!("Failed to enumerate input devices: {e}");
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Resolve `config.audio.device` ("default", `"#<index>"`, or a
+/// case-insensitive substring of a device name) to a concrete cpal
+/// `Device`. Falls back to the host's default input device — logging a
+/// warning — if the name or index matches nothing, rather than failing
+/// the whole recorder.
+fn select_input_device(host: &cpal::Host, device_config: &str) -> Result<cpal::Device, String> {
+    if device_config.is_empty() || device_config.eq_ignore_ascii_case("default") {
+        return host.default_input_device().ok_or_else(|| "No input audio device available".to_string());
+    }
+
+    if let Some(index) = device_config.strip_prefix('#').and_then(|s| s.parse::<usize>().ok()) {
+        let matched = host.input_devices().ok().and_then(|mut devices| devices.nth(index));
+        return match matched {
+            Some(device) => Ok(device),
+            None => {
+                warn!("No input device at index {index}, falling back to default");
+                host.default_input_device().ok_or_else(|| "No input audio device available".to_string())
+            }
+        };
+    }
+
+    let wanted = device_config.to_lowercase();
+    let matched = host.input_devices().ok().and_then(|mut devices| {
+        devices.find(|d| {
+            d.name()
+                .map(|name| name.to_lowercase().contains(&wanted))
+                .unwrap_or(false)
+        })
+    });
+
+    match matched {
+        Some(device) => Ok(device),
+        None => {
+            warn!("No input device matching \"{device_config}\" found, falling back to default");
+            host.default_input_device().ok_or_else(|| "No input audio device available".to_string())
+        }
+    }
+}
+
+/// Query `device`'s actual supported input configs and pick one close to
+/// `desired_sample_rate`/`desired_channels`, rather than assuming the
+/// device accepts our preferred format outright. Prefers an F32 config
+/// whose range covers the desired channel count and sample rate; failing
+/// that, any F32 config at its own max sample rate; failing that, the
+/// device's own reported default.
+fn negotiate_input_config(
+    device: &cpal::Device,
+    desired_sample_rate: u32,
+    desired_channels: u16,
+) -> Result<cpal::SupportedStreamConfig, String> {
+    let supported: Vec<cpal::SupportedStreamConfigRange> = device
+        .supported_input_configs()
+        .map_err(|e| format!("Failed to query supported input configs: {e}"))?
+        .filter(|c| c.sample_format() == cpal::SampleFormat::F32)
+        .collect();
+
+    if let Some(range) = supported.iter().find(|c| {
+        c.channels() == desired_channels
+            && c.min_sample_rate().0 <= desired_sample_rate
+            && c.max_sample_rate().0 >= desired_sample_rate
+    }) {
+        return Ok(range.clone().with_sample_rate(SampleRate(desired_sample_rate)));
+    }
+
+    if let Some(range) = supported.into_iter().next() {
+        return Ok(range.with_max_sample_rate());
+    }
+
+    device
+        .default_input_config()
+        .map_err(|e| format!("No supported input config found: {e}"))
+}
+
+/// Average all channels of each frame down to a single mono channel.
+/// No-op (besides copying) when `channels <= 1`.
+fn downmix_to_mono(data: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return data.to_vec();
+    }
+    data.chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+/// Linear resampler from `in_rate` to `out_rate`, carrying fractional
+/// state in `inner` across calls so block boundaries don't click.
+///
+/// For each output sample at position `pos` (in input-domain sample
+/// units), interpolates between the input samples at `floor(pos)` and
+/// `floor(pos) + 1`, then advances `pos` by `in_rate / out_rate`. Whatever
+/// input tail doesn't yet have a following sample to interpolate against
+/// is kept in `resample_carry` for the next callback.
+fn resample_to_target(inner: &mut RecorderInner, mono: &[f32], in_rate: f64, out_rate: f64) -> Vec<f32> {
+    if (in_rate - out_rate).abs() < f64::EPSILON {
+        return mono.to_vec();
+    }
+
+    let mut combined = std::mem::take(&mut inner.resample_carry);
+    combined.extend_from_slice(mono);
+
+    let ratio = in_rate / out_rate;
+    let mut pos = inner.resample_pos;
+    let mut out = Vec::new();
+
+    while (pos.floor() as usize) + 1 < combined.len() {
+        let idx = pos.floor() as usize;
+        let frac = (pos - idx as f64) as f32;
+        out.push(combined[idx] + (combined[idx + 1] - combined[idx]) * frac);
+        pos += ratio;
+    }
+
+    let carry_start = (pos.floor() as usize).min(combined.len());
+    inner.resample_pos = pos - carry_start as f64;
+    inner.resample_carry = combined[carry_start..].to_vec();
+
+    out
 }
 
 /// Calculate RMS energy of audio samples.
@@ -216,3 +509,4 @@ fn rms_energy(samples: &[f32]) -> f32 {
     let sum_sq: f32 = samples.iter().map(|s| s * s).sum();
     (sum_sq / samples.len() as f32).sqrt()
 }
+
@@ -14,16 +14,19 @@ use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
 use chrono::Local;
+use regex::Regex;
 use serde_json::json;
 use tokio::sync::{mpsc, Notify};
 use tracing::{debug, info, warn};
 
-use crate::config::Config;
+use crate::api::{self, TranscriptionApiState};
+use crate::commands::{self, CommandAction};
+use crate::config::{Config, ConfigHandle, VocabularyFilterMethod};
 use crate::history::{self, TranscriptionRecord};
-use crate::hotkey::{HotkeyEvent, HotkeyMonitor};
-use crate::processor::OllamaProcessor;
-use crate::recorder::AudioRecorder;
-use crate::transcriber::WhisperTranscriber;
+use crate::hotkey::{HotkeyEvent, HotkeyMonitor, HotkeyStatus};
+use crate::processor::TextProcessor;
+use crate::recorder::{AudioRecorder, AudioStatus};
+use crate::transcriber::{StreamingSession, WhisperTranscriber};
 use crate::typer::TextTyper;
 
 /// MCP state file path.
@@ -179,12 +182,86 @@ fn load_corrections() -> HashMap<String, String> {
     }
 }
 
+/// Load redaction terms from `.whisper/vocabulary_filter.txt` (one
+/// word/phrase per line).
+fn load_vocabulary_filter() -> Vec<String> {
+    let path = PathBuf::from(".whisper/vocabulary_filter.txt");
+    match fs::read_to_string(&path) {
+        Ok(contents) => {
+            let terms: Vec<String> = contents
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_string)
+                .collect();
+            if !terms.is_empty() {
+                info!(
+                    "Loaded {} vocabulary filter terms from .whisper/vocabulary_filter.txt",
+                    terms.len()
+                );
+            }
+            terms
+        }
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Build a single case-insensitive, word-boundary regex matching any of
+/// `terms`, longest first so a multi-word phrase wins over its component
+/// words. Returns `None` when there's nothing to filter.
+fn build_filter_regex(terms: &[String]) -> Option<Regex> {
+    if terms.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<&String> = terms.iter().collect();
+    sorted.sort_by_key(|t| std::cmp::Reverse(t.len()));
+    let pattern = sorted
+        .iter()
+        .map(|t| format!(r"\b{}\b", regex::escape(t)))
+        .collect::<Vec<_>>()
+        .join("|");
+    match Regex::new(&format!("(?i){pattern}")) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            warn!("Failed to build vocabulary filter regex: {e}");
+            None
+        }
+    }
+}
+
+/// Redact `terms` from `text` per `method`, matching case-insensitively on
+/// word boundaries so multi-word phrases are supported without catching
+/// partial-word hits (e.g. a filtered "ann" won't also match "announce").
+fn apply_term_filter(text: &str, terms: &[String], method: VocabularyFilterMethod, tag_marker: &str) -> String {
+    let Some(re) = build_filter_regex(terms) else {
+        return text.to_string();
+    };
+    let replaced = re.replace_all(text, |_: &regex::Captures| match method {
+        VocabularyFilterMethod::Mask => "***".to_string(),
+        VocabularyFilterMethod::Remove => String::new(),
+        VocabularyFilterMethod::Tag => tag_marker.to_string(),
+    });
+    if method == VocabularyFilterMethod::Remove {
+        replaced.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        replaced.into_owned()
+    }
+}
+
 pub struct DictationService {
     config: Config,
+    /// Live handle on the watched config file, if `Config::watch` found one
+    /// to watch; `refresh_config` pulls a fresh snapshot through it so a
+    /// saved edit to a setting read from `config` at use (the final
+    /// `silence.threshold` gate, `ollama.skip_threshold`, filter and
+    /// command settings, ...) takes effect without restarting the daemon.
+    /// The recorder (VAD and auto-stop settings), text processor (Ollama
+    /// model/backend) and typer keep the settings they were built with.
+    config_handle: Option<ConfigHandle>,
     state: ServiceState,
     recorder: AudioRecorder,
     transcriber: WhisperTranscriber,
-    processor: OllamaProcessor,
+    processor: Box<dyn TextProcessor>,
     typer: TextTyper,
     output_mode: OutputMode,
     recent_transcriptions: Vec<String>,
@@ -194,6 +271,23 @@ pub struct DictationService {
     vocabulary: Arc<RwLock<String>>,
     /// Ollama correction mappings from .whisper/corrections.yaml
     corrections: Arc<RwLock<HashMap<String, String>>>,
+    /// Redaction terms from .whisper/vocabulary_filter.txt
+    filter_terms: Arc<RwLock<Vec<String>>>,
+    /// Voice command grammar from .whisper/commands.yaml
+    commands: Arc<RwLock<HashMap<String, CommandAction>>>,
+    /// Optional OTLP metrics sink; `None` unless `config.telemetry.enabled`.
+    telemetry: Option<Arc<crate::telemetry::DictationMetrics>>,
+    /// Live-caption state for the in-progress recording; `None` unless
+    /// `config.recording.streaming` is enabled and a recording is active.
+    streaming: Option<LiveCaption>,
+}
+
+/// Tracks one recording's streaming transcription session alongside how much
+/// of the recorder's buffer has already been handed to it, so each poll only
+/// feeds the newly captured tail.
+struct LiveCaption {
+    session: StreamingSession,
+    fed_samples: usize,
 }
 
 impl DictationService {
@@ -201,13 +295,24 @@ impl DictationService {
         config: Config,
         transcriber: WhisperTranscriber,
         output_mode: OutputMode,
+        telemetry: Option<Arc<crate::telemetry::DictationMetrics>>,
+        config_handle: Option<ConfigHandle>,
     ) -> Self {
         let recorder = AudioRecorder::new(
             config.audio.clone(),
             config.recording.clone(),
             config.silence.clone(),
         );
-        let processor = OllamaProcessor::new(config.ollama.clone());
+        let processor = crate::processor::create(&config.ollama);
+        if config.ollama.enabled && config.ollama.backend == crate::config::CorrectionBackend::Ollama {
+            let ollama_config = config.ollama.clone();
+            tokio::spawn(async move {
+                let preflight = crate::processor::OllamaProcessor::new(ollama_config);
+                if let Err(e) = preflight.ensure_model_ready().await {
+                    warn!("Ollama preflight check failed: {e}");
+                }
+            });
+        }
         let typer = TextTyper::new(&config.typer);
         let voice_gate = VoiceGate::new();
 
@@ -219,9 +324,12 @@ impl DictationService {
 
         let vocabulary = Arc::new(RwLock::new(load_vocabulary()));
         let corrections = Arc::new(RwLock::new(load_corrections()));
+        let filter_terms = Arc::new(RwLock::new(load_vocabulary_filter()));
+        let commands = Arc::new(RwLock::new(commands::load_commands()));
 
         let svc = Self {
             config,
+            config_handle,
             state: ServiceState::Idle,
             recorder,
             transcriber,
@@ -233,6 +341,10 @@ impl DictationService {
             tts_cancel_client,
             vocabulary,
             corrections,
+            filter_terms,
+            commands,
+            telemetry,
+            streaming: None,
         };
         svc.write_mcp_state();
         svc
@@ -291,17 +403,37 @@ impl DictationService {
 
         // Create hotkey channel
         let (hotkey_tx, mut hotkey_rx) = mpsc::channel::<HotkeyEvent>(16);
+        let (hotkey_status_tx, mut hotkey_status_rx) = mpsc::channel::<HotkeyStatus>(16);
 
         // Start hotkey monitor in background
-        let hotkey_monitor = HotkeyMonitor::new(&self.config.hotkey, hotkey_tx);
+        let hotkey_monitor = HotkeyMonitor::new(&self.config.hotkey, hotkey_tx, hotkey_status_tx)?;
         tokio::spawn(async move {
             hotkey_monitor.run().await;
         });
 
+        // Optional local OpenAI-compatible transcription endpoint, so other
+        // tools can reuse this already-loaded model instead of spawning
+        // their own Whisper instance.
+        if self.config.api.enabled {
+            let api_state = TranscriptionApiState {
+                transcriber: self.transcriber.clone(),
+                vocabulary: self.vocabulary.clone(),
+                voice_gate: self.voice_gate.clone(),
+            };
+            api::start_api_server(api_state, self.config.api.port).await;
+        }
+
         info!("Service ready — press hotkey to start recording (mode: {:?})", self.output_mode);
 
         // Auto-stop poll interval
         let mut auto_stop_interval = tokio::time::interval(tokio::time::Duration::from_millis(100));
+        // Live-caption poll interval; only acts while `self.streaming` is set.
+        let mut streaming_interval = tokio::time::interval(tokio::time::Duration::from_millis(
+            self.config.recording.streaming_poll_interval_ms,
+        ));
+        // Audio stream recovery poll interval; rebuilds a dropped/errored
+        // stream with backoff (see `AudioRecorder::poll_recovery`).
+        let mut stream_health_interval = tokio::time::interval(tokio::time::Duration::from_millis(250));
 
         loop {
             tokio::select! {
@@ -315,6 +447,13 @@ impl DictationService {
                         }
                     }
                 }
+                status = hotkey_status_rx.recv() => {
+                    match status {
+                        Some(HotkeyStatus::DeviceConnected(name)) => info!("Keyboard connected: {name}"),
+                        Some(HotkeyStatus::DeviceDisconnected(name)) => warn!("Keyboard disconnected: {name}"),
+                        None => {} // monitor task exited; hotkey_rx closing will end the service
+                    }
+                }
                 _ = auto_stop_interval.tick() => {
                     // Check for silence-triggered auto-stop
                     if self.state == ServiceState::Recording && self.recorder.should_auto_stop() {
@@ -322,6 +461,16 @@ impl DictationService {
                         self.on_hotkey_release().await;
                     }
                 }
+                _ = streaming_interval.tick() => {
+                    self.on_streaming_tick().await;
+                }
+                _ = stream_health_interval.tick() => {
+                    match self.recorder.poll_recovery() {
+                        Some(AudioStatus::Degraded(err)) => warn!("Audio stream degraded: {err}; attempting recovery"),
+                        Some(AudioStatus::Recovered) => info!("Audio stream recovered"),
+                        None => {}
+                    }
+                }
             }
         }
 
@@ -341,19 +490,78 @@ impl DictationService {
 
         self.state = ServiceState::Recording;
         self.recorder.start();
+
+        if self.config.recording.streaming {
+            self.streaming = Some(LiveCaption {
+                session: self.transcriber.start_streaming(self.config.whisper.stability_threshold),
+                fed_samples: 0,
+            });
+        }
+
         info!("State: IDLE → RECORDING");
     }
 
+    /// Re-run Whisper over whatever of the recording buffer hasn't been fed
+    /// to the live-caption session yet, and type any words that just became
+    /// stable. No-op unless `config.recording.streaming` started a session
+    /// for the in-progress recording.
+    async fn on_streaming_tick(&mut self) {
+        if self.state != ServiceState::Recording {
+            return;
+        }
+        let Some(mut live) = self.streaming.take() else {
+            return;
+        };
+
+        let new_samples = self.recorder.snapshot_from(live.fed_samples);
+        if new_samples.is_empty() {
+            self.streaming = Some(live);
+            return;
+        }
+        live.fed_samples += new_samples.len();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let words = live.session.push_audio(&new_samples);
+            (live, words)
+        })
+        .await;
+
+        match result {
+            Ok((live, Ok(words))) => {
+                self.streaming = Some(live);
+                if !words.is_empty() {
+                    self.typer.type_text(&format!("{} ", words.join(" ")));
+                }
+            }
+            Ok((live, Err(e))) => {
+                warn!("Live-caption pass failed: {e}");
+                self.streaming = Some(live);
+            }
+            Err(e) => warn!("Live-caption task panicked: {e}"),
+        }
+    }
+
     async fn on_hotkey_release(&mut self) {
         if self.state != ServiceState::Recording {
             return;
         }
 
+        // Pick up any saved config edit before acting on thresholds/modes
+        // below (see `refresh_config`).
+        self.refresh_config();
+
         let t_start = Instant::now();
         self.state = ServiceState::Processing;
         info!("State: RECORDING → PROCESSING");
 
-        let samples = self.recorder.stop();
+        let mut samples = self.recorder.stop();
+        crate::preprocess::process(&mut samples, &self.config.preprocess, self.recorder.sample_rate());
+        // Audio captured since the live-caption session's last poll, so its
+        // final pass covers the whole utterance even if released mid-tick.
+        let live_caption = self.streaming.take().map(|live| {
+            let trailing = samples[live.fed_samples.min(samples.len())..].to_vec();
+            (live, trailing)
+        });
 
         if samples.is_empty() {
             info!("No audio captured, returning to IDLE");
@@ -370,10 +578,31 @@ impl DictationService {
 
         let audio_duration = samples.len() as f64 / self.recorder.sample_rate() as f64;
         info!("Captured {:.1}s of audio ({} samples)", audio_duration, samples.len());
+        let timestamp = Local::now().format("%Y-%m-%dT%H:%M:%S%.6f").to_string();
 
         // Check if vocabulary/corrections need reloading (MCP tools set flags in state file)
         self.check_whisper_reload();
 
+        // --- Audio archival ---
+        // Written before Whisper consumes `samples` below, and only past the
+        // empty/silent checks above, so the archive never fills with
+        // zero-content recordings (the lasprs pattern).
+        let audio_path = if self.config.audio_archive.enabled {
+            let archive_samples = samples.clone();
+            let sample_rate = self.recorder.sample_rate();
+            let max_files = self.config.audio_archive.max_files;
+            let archive_timestamp = timestamp.clone();
+            tokio::task::spawn_blocking(move || {
+                history::save_audio_wav(&archive_samples, sample_rate, &archive_timestamp, max_files)
+            })
+            .await
+            .ok()
+            .flatten()
+            .map(|p| p.display().to_string())
+        } else {
+            None
+        };
+
         // --- Whisper transcription ---
         let t_whisper_start = Instant::now();
         let transcriber = self.transcriber.clone();
@@ -432,6 +661,25 @@ impl DictationService {
             return;
         }
 
+        // --- Voice command dispatch ---
+        // A short utterance matching a configured trigger phrase acts
+        // instead of going through Ollama/typing, so commands chain
+        // back-to-back without that pipeline's latency between them.
+        if self.config.commands.enabled {
+            let command_phrase = commands::normalize(&raw_text);
+            let registered = self.commands.read().unwrap().clone();
+            if let Some(action) = commands::match_command(
+                &command_phrase,
+                &registered,
+                self.config.commands.fuzzy_distance,
+            ) {
+                info!("Voice command matched: \"{raw_text}\" -> {action:?}");
+                self.dispatch_command(action).await;
+                self.transition_to_idle();
+                return;
+            }
+        }
+
         // --- Ollama correction ---
         let t_ollama_start = Instant::now();
         let corrections = self.corrections.read().unwrap().clone();
@@ -456,18 +704,53 @@ impl DictationService {
         let raw_clean = strip_trailing_thankyou(&raw_text);
         let processed_clean = processed_text.as_deref().map(|t| strip_trailing_thankyou(t));
 
+        // --- Vocabulary filter (redaction) ---
+        // Applied after Ollama correction but before typing, so corrected
+        // and raw output are both scrubbed of whatever the user listed in
+        // .whisper/vocabulary_filter.txt.
+        let filter_terms = self.filter_terms.read().unwrap().clone();
+        let filter_method = self.config.filter.method;
+        let raw_clean = apply_term_filter(raw_clean, &filter_terms, filter_method, &self.config.filter.tag_marker);
+        let processed_clean = processed_clean
+            .map(|t| apply_term_filter(t, &filter_terms, filter_method, &self.config.filter.tag_marker));
+
         // Build final output
         let final_text = match self.output_mode {
             OutputMode::Whisper => format!("{raw_clean} "),
-            OutputMode::Ollama => format!("{} ", processed_clean.unwrap_or(raw_clean)),
+            OutputMode::Ollama => format!("{} ", processed_clean.as_deref().unwrap_or(&raw_clean)),
             OutputMode::Both => {
-                format!("{} [{raw_clean}] ", processed_clean.unwrap_or(raw_clean))
+                format!("{} [{raw_clean}] ", processed_clean.as_deref().unwrap_or(&raw_clean))
             }
         };
 
         // --- Type into active window ---
+        //
+        // If live captioning was on, the stable prefix was already typed
+        // word-by-word during RECORDING (see `on_streaming_tick`); here we
+        // only commit whatever tail never crossed the stability threshold,
+        // via one final full-quality pass over the whole buffer. Otherwise
+        // this is the normal batch path: type the whole `final_text` now.
         let t_type_start = Instant::now();
-        self.typer.type_text(&final_text);
+        match live_caption {
+            Some((mut live, trailing)) => {
+                let finish_result = tokio::task::spawn_blocking(move || {
+                    if !trailing.is_empty() {
+                        let _ = live.session.push_audio(&trailing);
+                    }
+                    live.session.finish()
+                })
+                .await;
+                match finish_result {
+                    Ok(Ok(words)) if !words.is_empty() => {
+                        self.typer.type_text(&format!("{} ", words.join(" ")));
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => warn!("Live-caption finish pass failed: {e}"),
+                    Err(e) => warn!("Live-caption finish task panicked: {e}"),
+                }
+            }
+            None => self.typer.type_text(&final_text),
+        }
         let t_type = t_type_start.elapsed().as_secs_f64() * 1000.0;
 
         let t_total = t_start.elapsed().as_secs_f64() * 1000.0;
@@ -488,7 +771,7 @@ impl DictationService {
         };
 
         let record = TranscriptionRecord {
-            timestamp: Local::now().format("%Y-%m-%dT%H:%M:%S%.6f").to_string(),
+            timestamp,
             whisper_text: raw_text,
             ollama_text: if t_ollama > 0.0 { ollama_text } else { None },
             final_text: final_text.clone(),
@@ -501,9 +784,21 @@ impl DictationService {
             char_count: final_text.len(),
             word_count: final_text.split_whitespace().count(),
             speed_ratio,
+            audio_path,
         };
         history::save_record(&record);
 
+        if let Some(metrics) = &self.telemetry {
+            metrics.record(
+                t_whisper,
+                if t_ollama > 0.0 { Some(t_ollama) } else { None },
+                t_type,
+                t_total,
+                speed_ratio,
+                self.output_mode.as_str(),
+            );
+        }
+
         self.transition_to_idle();
     }
 
@@ -515,6 +810,37 @@ impl DictationService {
         info!("State: → IDLE");
     }
 
+    /// Dispatch a matched voice command's action in place of typing text.
+    async fn dispatch_command(&mut self, action: &CommandAction) {
+        match action {
+            CommandAction::KeyPress { key } => self.typer.press_key(key),
+            CommandAction::KeyChord { modifiers, key } => self.typer.press_chord(modifiers, key),
+            CommandAction::SetOutputMode { mode } => {
+                self.output_mode = OutputMode::from_str(mode);
+                self.write_mcp_state();
+                info!("Voice command set output mode to {:?}", self.output_mode);
+            }
+            CommandAction::ExecShell { command } => {
+                let command = command.clone();
+                tokio::task::spawn_blocking(move || {
+                    if let Err(e) = std::process::Command::new("sh").arg("-c").arg(&command).status() {
+                        warn!("Voice command exec failed: {e}");
+                    }
+                });
+            }
+        }
+    }
+
+    /// Pull in the latest config if `Config::watch` found a file to watch,
+    /// so a saved edit takes effect on the very next utterance instead of
+    /// requiring a restart. No-op (keeps the startup snapshot) if no
+    /// config file was found to watch in the first place.
+    fn refresh_config(&mut self) {
+        if let Some(handle) = &self.config_handle {
+            self.config = handle.current();
+        }
+    }
+
     /// Check state file for vocabulary/corrections reload flags (set by MCP tools).
     fn check_whisper_reload(&self) {
         let state = {
@@ -550,5 +876,31 @@ impl DictationService {
                 }
             }
         }
+
+        if state.get("filter_updated").and_then(|v| v.as_bool()) == Some(true) {
+            let new_terms = load_vocabulary_filter();
+            *self.filter_terms.write().unwrap() = new_terms;
+            // Clear the flag
+            let path = state_file();
+            if let Ok(contents) = fs::read_to_string(&path) {
+                if let Ok(mut s) = serde_json::from_str::<serde_json::Value>(&contents) {
+                    s.as_object_mut().map(|o| o.remove("filter_updated"));
+                    let _ = fs::write(&path, serde_json::to_string_pretty(&s).unwrap());
+                }
+            }
+        }
+
+        if state.get("commands_updated").and_then(|v| v.as_bool()) == Some(true) {
+            let new_commands = commands::load_commands();
+            *self.commands.write().unwrap() = new_commands;
+            // Clear the flag
+            let path = state_file();
+            if let Ok(contents) = fs::read_to_string(&path) {
+                if let Ok(mut s) = serde_json::from_str::<serde_json::Value>(&contents) {
+                    s.as_object_mut().map(|o| o.remove("commands_updated"));
+                    let _ = fs::write(&path, serde_json::to_string_pretty(&s).unwrap());
+                }
+            }
+        }
     }
 }
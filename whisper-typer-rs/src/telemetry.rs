@@ -0,0 +1,122 @@
+//! Optional OpenTelemetry OTLP export of dictation latency metrics.
+//!
+//! Disabled by default — when `config.telemetry.enabled` is false, [`init`]
+//! returns `None` and nothing is recorded, so the common build has no
+//! collector dependency at runtime. When enabled, per-transcription timing
+//! (`whisper_latency_ms`, `ollama_latency_ms`, `typing_latency_ms`,
+//! `total_latency_ms`, `speed_ratio`) is pushed to a collector as
+//! histograms/counters so p50/p95 latency and throughput can be graphed
+//! over time, instead of hand-parsing the JSONL history.
+
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::Resource;
+use tracing::{info, warn};
+
+use crate::config::TelemetryConfig;
+
+/// Histogram/counter instruments for per-transcription latency metrics,
+/// exported to an OTLP collector.
+pub struct DictationMetrics {
+    // Kept alive for the life of the process; dropping it stops export.
+    _provider: SdkMeterProvider,
+    transcriptions: Counter<u64>,
+    whisper_latency_ms: Histogram<f64>,
+    ollama_latency_ms: Histogram<f64>,
+    typing_latency_ms: Histogram<f64>,
+    total_latency_ms: Histogram<f64>,
+    speed_ratio: Histogram<f64>,
+}
+
+impl DictationMetrics {
+    /// Record one completed transcription's timing breakdown.
+    pub fn record(
+        &self,
+        whisper_ms: f64,
+        ollama_ms: Option<f64>,
+        typing_ms: f64,
+        total_ms: f64,
+        speed_ratio: f64,
+        output_mode: &str,
+    ) {
+        let attrs = [KeyValue::new("output_mode", output_mode.to_string())];
+        self.transcriptions.add(1, &attrs);
+        self.whisper_latency_ms.record(whisper_ms, &attrs);
+        if let Some(ollama_ms) = ollama_ms {
+            self.ollama_latency_ms.record(ollama_ms, &attrs);
+        }
+        self.typing_latency_ms.record(typing_ms, &attrs);
+        self.total_latency_ms.record(total_ms, &attrs);
+        self.speed_ratio.record(speed_ratio, &attrs);
+    }
+}
+
+/// Initialize the OTLP metrics pipeline if `config.enabled`. Returns
+/// `None` when telemetry is disabled, or (with a logged warning) when the
+/// exporter fails to build.
+pub fn init(config: &TelemetryConfig) -> Option<DictationMetrics> {
+    if !config.enabled {
+        return None;
+    }
+
+    let exporter = match opentelemetry_otlp::MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(&config.otlp_endpoint)
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            warn!("Failed to build OTLP metric exporter: {e}");
+            return None;
+        }
+    };
+
+    let resource = Resource::builder()
+        .with_attribute(KeyValue::new("service.name", config.service_name.clone()))
+        .build();
+
+    let provider = SdkMeterProvider::builder()
+        .with_periodic_exporter(exporter)
+        .with_resource(resource)
+        .build();
+
+    let meter = provider.meter("whisper-typer");
+    let metrics = DictationMetrics {
+        transcriptions: meter
+            .u64_counter("dictation.transcriptions")
+            .with_description("Count of completed dictation transcriptions")
+            .build(),
+        whisper_latency_ms: meter
+            .f64_histogram("dictation.whisper_latency_ms")
+            .with_description("Whisper transcription latency")
+            .with_unit("ms")
+            .build(),
+        ollama_latency_ms: meter
+            .f64_histogram("dictation.ollama_latency_ms")
+            .with_description("Ollama correction latency")
+            .with_unit("ms")
+            .build(),
+        typing_latency_ms: meter
+            .f64_histogram("dictation.typing_latency_ms")
+            .with_description("Keyboard typing latency")
+            .with_unit("ms")
+            .build(),
+        total_latency_ms: meter
+            .f64_histogram("dictation.total_latency_ms")
+            .with_description("End-to-end dictation latency")
+            .with_unit("ms")
+            .build(),
+        speed_ratio: meter
+            .f64_histogram("dictation.speed_ratio")
+            .with_description("Audio duration divided by total latency")
+            .build(),
+        _provider: provider,
+    };
+
+    info!(
+        "OTLP telemetry enabled: endpoint={} service={}",
+        config.otlp_endpoint, config.service_name
+    );
+    Some(metrics)
+}
@@ -6,10 +6,10 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
-use tracing::info;
+use tracing::{info, warn};
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
-use crate::config::WhisperConfig;
+use crate::config::{DecodingStrategy, VocabularyFilterMethod, WhisperConfig};
 
 /// Thread-safe wrapper around WhisperContext.
 /// WhisperContext is Send+Sync, so we wrap it in Arc for sharing.
@@ -17,12 +17,30 @@ use crate::config::WhisperConfig;
 pub struct WhisperTranscriber {
     ctx: Arc<WhisperContext>,
     model_path: PathBuf,
+    config: WhisperConfig,
 }
 
 /// Result of a transcription with timing info.
 pub struct TranscribeResult {
     pub text: String,
     pub latency_ms: f64,
+    /// Language code actually decoded in (detected, when `language = "auto"`).
+    pub detected_language: String,
+    /// Confidence of the language detection pass, 0.0 when `language` was
+    /// pinned to an explicit code (no detection ran).
+    pub language_confidence: f32,
+    /// Per-token timing/confidence, populated only when
+    /// `WhisperConfig::word_timestamps` is enabled; empty otherwise.
+    pub words: Vec<WordTiming>,
+}
+
+/// Timing and confidence for a single decoded token, surfaced when
+/// `WhisperConfig::word_timestamps` is enabled.
+pub struct WordTiming {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub probability: f32,
 }
 
 impl WhisperTranscriber {
@@ -43,49 +61,101 @@ impl WhisperTranscriber {
         Ok(Self {
             ctx: Arc::new(ctx),
             model_path,
+            config: config.clone(),
         })
     }
 
     /// Transcribe audio samples (f32, 16kHz, mono) to text.
-    pub fn transcribe(&self, samples: &[f32]) -> Result<TranscribeResult, String> {
+    ///
+    /// `extra_prompt` (e.g. a `.whisper/vocabulary.txt` prompt built by the
+    /// service) is appended to the configured `vocabulary`/`initial_prompt`
+    /// so callers can layer per-project terms on top of the static config.
+    pub fn transcribe(&self, samples: &[f32], extra_prompt: Option<&str>) -> Result<TranscribeResult, String> {
         let t0 = Instant::now();
 
-        let mut state = self
-            .ctx
-            .create_state()
-            .map_err(|e| format!("Failed to create whisper state: {e}"))?;
+        let (language, language_confidence) = if self.config.language.eq_ignore_ascii_case("auto") {
+            match self.detect_language(samples) {
+                Ok((code, confidence)) => {
+                    info!("Detected language: {code} ({confidence:.2} confidence)");
+                    (code, confidence)
+                }
+                Err(e) => {
+                    warn!("Language detection failed: {e}, defaulting to en");
+                    ("en".to_string(), 0.0)
+                }
+            }
+        } else {
+            (self.config.language.clone(), 0.0)
+        };
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-        params.set_language(Some("en"));
-        params.set_print_special(false);
-        params.set_print_progress(false);
-        params.set_print_realtime(false);
-        params.set_print_timestamps(false);
-        params.set_single_segment(true);
-        params.set_token_timestamps(false);
+        let prompt = self.build_initial_prompt(extra_prompt);
 
-        state
-            .full(params, samples)
-            .map_err(|e| format!("Whisper inference failed: {e}"))?;
+        // Initial decode at the configured strategy, then retry at
+        // increasing temperatures (whisper.cpp's fallback ladder) if the
+        // result looks low-confidence or repetitive.
+        let mut fallback_count = 0usize;
+        let mut temperature: Option<f32> = None;
+        let (mut text, mut avg_logprob, mut compression_ratio, mut words);
+        loop {
+            let strategy = match temperature {
+                None => self.sampling_strategy(),
+                Some(_) => SamplingStrategy::Greedy { best_of: 1 },
+            };
 
-        // Collect all segments into a single string
-        let n_segments = state.full_n_segments();
+            let mut state = self
+                .ctx
+                .create_state()
+                .map_err(|e| format!("Failed to create whisper state: {e}"))?;
 
-        let mut text = String::new();
-        for i in 0..n_segments {
-            if let Some(segment) = state.get_segment(i) {
-                if let Ok(segment_text) = segment.to_str_lossy() {
-                    let trimmed = segment_text.trim();
-                    if !trimmed.is_empty() {
-                        if !text.is_empty() {
-                            text.push(' ');
-                        }
-                        text.push_str(trimmed);
-                    }
-                }
+            let mut params = FullParams::new(strategy);
+            params.set_language(Some(&language));
+            params.set_print_special(false);
+            params.set_print_progress(false);
+            params.set_print_realtime(false);
+            params.set_print_timestamps(false);
+            params.set_single_segment(true);
+            params.set_token_timestamps(self.config.word_timestamps);
+            if !prompt.is_empty() {
+                params.set_initial_prompt(&prompt);
+            }
+            if let Some(t) = temperature {
+                params.set_temperature(t);
+            }
+
+            state
+                .full(params, samples)
+                .map_err(|e| format!("Whisper inference failed: {e}"))?;
+
+            let (collected, logprob, collected_words) =
+                collect_segments(&state, self.config.word_timestamps);
+            text = collected;
+            avg_logprob = logprob;
+            words = collected_words;
+            compression_ratio = estimate_compression_ratio(&text);
+
+            let low_confidence = avg_logprob < self.config.logprob_threshold
+                || compression_ratio > self.config.compression_ratio_threshold;
+
+            if !low_confidence || fallback_count >= self.config.temperature_fallback.len() {
+                break;
             }
+
+            temperature = Some(self.config.temperature_fallback[fallback_count]);
+            fallback_count += 1;
+            info!(
+                "Low-confidence decode (avg_logprob={avg_logprob:.2}, compression_ratio={compression_ratio:.2}), \
+                 retrying at temperature {:.1} (fallback {fallback_count}/{})",
+                temperature.unwrap(),
+                self.config.temperature_fallback.len()
+            );
         }
 
+        let text = if self.config.filter_words.is_empty() {
+            text
+        } else {
+            apply_vocabulary_filter(&text, &self.config.filter_words, self.config.filter_method)
+        };
+
         let latency_ms = t0.elapsed().as_secs_f64() * 1000.0;
         let audio_duration = samples.len() as f64 / 16000.0;
         let rtf = if audio_duration > 0.0 {
@@ -95,14 +165,70 @@ impl WhisperTranscriber {
         };
 
         info!(
-            "Transcribed {:.1}s audio in {:.0}ms (RTF: {:.2}x): \"{}\"",
+            "Transcribed {:.1}s audio in {:.0}ms (RTF: {:.2}x, strategy: {:?}, fallbacks: {fallback_count}): \"{}\"",
             audio_duration,
             latency_ms,
             rtf,
+            self.config.decoding,
             truncate_preview(&text, 80)
         );
 
-        Ok(TranscribeResult { text, latency_ms })
+        Ok(TranscribeResult {
+            text,
+            latency_ms,
+            detected_language: language,
+            language_confidence,
+            words,
+        })
+    }
+
+    fn sampling_strategy(&self) -> SamplingStrategy {
+        match self.config.decoding {
+            DecodingStrategy::Greedy { best_of } => SamplingStrategy::Greedy { best_of },
+            DecodingStrategy::BeamSearch { beam_size, patience } => {
+                SamplingStrategy::BeamSearch { beam_size, patience }
+            }
+        }
+    }
+
+    /// Detect the spoken language from the leading ~30s of audio using
+    /// whisper.cpp's language-id pass. Returns (language code, confidence).
+    fn detect_language(&self, samples: &[f32]) -> Result<(String, f32), String> {
+        let mut state = self
+            .ctx
+            .create_state()
+            .map_err(|e| format!("Failed to create whisper state: {e}"))?;
+
+        let window_len = samples.len().min(16000 * 30);
+        state
+            .pcm_to_mel(&samples[..window_len], 1)
+            .map_err(|e| format!("Failed to compute mel spectrogram: {e}"))?;
+
+        let (lang_id, probs) = state
+            .lang_detect(0, 1)
+            .map_err(|e| format!("Language detection failed: {e}"))?;
+
+        let code = whisper_rs::whisper_lang_str(lang_id).to_string();
+        let confidence = probs.get(lang_id as usize).copied().unwrap_or(0.0);
+        Ok((code, confidence))
+    }
+
+    /// Combine the configured vocabulary/initial_prompt with a caller-supplied
+    /// prompt (e.g. per-project vocabulary) into one biasing string.
+    fn build_initial_prompt(&self, extra_prompt: Option<&str>) -> String {
+        let mut parts: Vec<&str> = Vec::new();
+        if !self.config.initial_prompt.is_empty() {
+            parts.push(&self.config.initial_prompt);
+        }
+        let vocab_line;
+        if !self.config.vocabulary.is_empty() {
+            vocab_line = self.config.vocabulary.join(", ");
+            parts.push(&vocab_line);
+        }
+        if let Some(extra) = extra_prompt.filter(|s| !s.is_empty()) {
+            parts.push(extra);
+        }
+        parts.join(" ")
     }
 
     #[allow(dead_code)]
@@ -110,6 +236,12 @@ impl WhisperTranscriber {
         &self.model_path
     }
 
+    /// Start a new incremental streaming session sharing this transcriber's
+    /// loaded model. See [`StreamingSession`] for the stabilization scheme.
+    pub fn start_streaming(&self, stability_threshold: u32) -> StreamingSession {
+        StreamingSession::new(self.ctx.clone(), stability_threshold)
+    }
+
     /// Find the GGML model file.
     fn find_model(model_name: &str) -> Result<PathBuf, String> {
         // Check if it's a direct path to an existing file
@@ -155,6 +287,299 @@ impl WhisperTranscriber {
     }
 }
 
+/// Minimum amount of newly appended audio (in samples at 16kHz) before a
+/// streaming session re-runs `full` over its growing buffer. ~250ms.
+const STREAMING_CHUNK_SAMPLES: usize = 16000 / 4;
+
+/// Incremental, stability-based streaming transcription session.
+///
+/// Feed it growing audio via `push_audio`; every ~200-300ms of newly
+/// appended audio it re-runs Whisper over the whole buffer and returns any
+/// words that have just become "stable" (unchanged across
+/// `stability_threshold` consecutive passes). Each word is surfaced exactly
+/// once, even though Whisper commonly revises the tail of its hypothesis
+/// between passes. Call `finish()` once silence is detected upstream to
+/// flush whatever remains.
+pub struct StreamingSession {
+    ctx: Arc<WhisperContext>,
+    buffer: Vec<f32>,
+    /// Sample count at buffer.len() as of the last `full` run.
+    last_run_len: usize,
+    stability_threshold: usize,
+    /// Per-item (word/token) run-length of how many consecutive passes it
+    /// has matched the same text at that index.
+    stable_runs: Vec<(String, u32)>,
+    /// Number of items already emitted to the caller.
+    partial_index: usize,
+}
+
+impl StreamingSession {
+    /// Start a new streaming session over the given model context.
+    pub fn new(ctx: Arc<WhisperContext>, stability_threshold: u32) -> Self {
+        Self {
+            ctx,
+            buffer: Vec::new(),
+            last_run_len: 0,
+            stability_threshold: stability_threshold.max(1) as usize,
+            stable_runs: Vec::new(),
+            partial_index: 0,
+        }
+    }
+
+    /// Append newly captured audio samples. Returns any items that just
+    /// became stable (empty most of the time, since a rerun only happens
+    /// every `STREAMING_CHUNK_SAMPLES` of new audio).
+    pub fn push_audio(&mut self, samples: &[f32]) -> Result<Vec<String>, String> {
+        self.buffer.extend_from_slice(samples);
+
+        if self.buffer.len() - self.last_run_len < STREAMING_CHUNK_SAMPLES {
+            return Ok(Vec::new());
+        }
+
+        self.run_pass()
+    }
+
+    /// Flush all remaining (not-yet-stable) items as final, after one last
+    /// full-quality pass over the complete buffer. Consumes the session.
+    pub fn finish(mut self) -> Result<Vec<String>, String> {
+        let items = self.hypothesize()?;
+        let remainder: Vec<String> = items[self.partial_index.min(items.len())..].to_vec();
+        Ok(remainder)
+    }
+
+    /// Re-run `full` over the whole buffer and diff against the previous
+    /// hypothesis, advancing `partial_index` past anything newly stable.
+    fn run_pass(&mut self) -> Result<Vec<String>, String> {
+        self.last_run_len = self.buffer.len();
+        let items = self.hypothesize()?;
+
+        // Align the new hypothesis against the previous run-length table.
+        let mut next_runs = Vec::with_capacity(items.len());
+        for (i, text) in items.iter().enumerate() {
+            let run = match self.stable_runs.get(i) {
+                Some((prev_text, count)) if prev_text == text => count + 1,
+                _ => 1,
+            };
+            next_runs.push((text.clone(), run));
+        }
+        self.stable_runs = next_runs;
+
+        // An item is stable once it has survived `stability_threshold`
+        // consecutive passes at the same index.
+        let stable_count = self
+            .stable_runs
+            .iter()
+            .take_while(|(_, run)| *run as usize >= self.stability_threshold)
+            .count();
+
+        if stable_count <= self.partial_index {
+            return Ok(Vec::new());
+        }
+
+        let newly_stable = self.stable_runs[self.partial_index..stable_count]
+            .iter()
+            .map(|(text, _)| text.clone())
+            .collect();
+        self.partial_index = stable_count;
+        Ok(newly_stable)
+    }
+
+    /// Run Whisper (multi-segment, no single-segment lock) over the full
+    /// buffer and tokenize the result into an ordered list of items.
+    fn hypothesize(&self) -> Result<Vec<String>, String> {
+        let mut state = self
+            .ctx
+            .create_state()
+            .map_err(|e| format!("Failed to create whisper state: {e}"))?;
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        params.set_language(Some("en"));
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+        params.set_single_segment(false);
+        params.set_token_timestamps(false);
+
+        state
+            .full(params, &self.buffer)
+            .map_err(|e| format!("Whisper inference failed: {e}"))?;
+
+        let n_segments = state.full_n_segments();
+        let mut text = String::new();
+        for i in 0..n_segments {
+            if let Some(segment) = state.get_segment(i) {
+                if let Ok(segment_text) = segment.to_str_lossy() {
+                    let trimmed = segment_text.trim();
+                    if !trimmed.is_empty() {
+                        if !text.is_empty() {
+                            text.push(' ');
+                        }
+                        text.push_str(trimmed);
+                    }
+                }
+            }
+        }
+
+        Ok(text.split_whitespace().map(str::to_string).collect())
+    }
+}
+
+/// Collect all segments into a single string, along with the mean
+/// log-probability across all decoded tokens (used by the temperature
+/// fallback ladder to judge decode quality) and, when `want_words` is set,
+/// a [`WordTiming`] per token (requires `set_token_timestamps(true)` to have
+/// been set on the params used for this pass).
+fn collect_segments(state: &whisper_rs::WhisperState, want_words: bool) -> (String, f32, Vec<WordTiming>) {
+    let n_segments = state.full_n_segments();
+
+    let mut text = String::new();
+    let mut logprob_sum = 0.0f64;
+    let mut token_count = 0u32;
+    let mut words = Vec::new();
+
+    for i in 0..n_segments {
+        if let Some(segment) = state.get_segment(i) {
+            if let Ok(segment_text) = segment.to_str_lossy() {
+                let trimmed = segment_text.trim();
+                if !trimmed.is_empty() {
+                    if !text.is_empty() {
+                        text.push(' ');
+                    }
+                    text.push_str(trimmed);
+                }
+            }
+        }
+
+        let n_tokens = state.full_n_tokens(i);
+        for t in 0..n_tokens {
+            if let Ok(prob) = state.full_get_token_prob(i, t) {
+                logprob_sum += f64::from(prob.max(f32::MIN_POSITIVE).ln());
+                token_count += 1;
+
+                if want_words {
+                    if let Ok(token_text) = state.full_get_token_text(i, t) {
+                        let trimmed = token_text.trim();
+                        // Whisper's special/control tokens (e.g. "[_BEG_]")
+                        // carry no timing worth surfacing.
+                        if !trimmed.is_empty() && !trimmed.starts_with('[') {
+                            if let Ok(data) = state.full_get_token_data(i, t) {
+                                words.push(WordTiming {
+                                    text: trimmed.to_string(),
+                                    start_ms: i64::from(data.t0) * 10,
+                                    end_ms: i64::from(data.t1) * 10,
+                                    probability: prob,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let avg_logprob = if token_count > 0 {
+        (logprob_sum / f64::from(token_count)) as f32
+    } else {
+        0.0
+    };
+
+    (text, avg_logprob, words)
+}
+
+/// Approximate whisper.cpp's `compression_ratio` check (text length over
+/// its gzip-compressed length) with a dependency-free run-length estimate —
+/// both flag the same failure mode: repetitive, looping output.
+fn estimate_compression_ratio(text: &str) -> f32 {
+    let bytes = text.as_bytes();
+    if bytes.is_empty() {
+        return 1.0;
+    }
+
+    let mut compressed_len = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let mut run = 1;
+        while i + run < bytes.len() && bytes[i + run] == b {
+            run += 1;
+        }
+        compressed_len += 2; // byte + run-length marker
+        i += run;
+    }
+
+    bytes.len() as f32 / compressed_len.max(1) as f32
+}
+
+/// Apply a post-decode vocabulary filter to `text`, matching `words`
+/// case-insensitively and handling each match per `method`.
+fn apply_vocabulary_filter(text: &str, words: &[String], method: VocabularyFilterMethod) -> String {
+    let mut result = text.to_string();
+    for word in words {
+        if word.is_empty() {
+            continue;
+        }
+        result = replace_case_insensitive(&result, word, method);
+    }
+    // `Remove` can leave doubled whitespace behind; collapse it.
+    if method == VocabularyFilterMethod::Remove {
+        result = result.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+    result
+}
+
+fn replace_case_insensitive(text: &str, term: &str, method: VocabularyFilterMethod) -> String {
+    if term.is_empty() {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some((start, end)) = find_case_insensitive(rest, term) {
+        out.push_str(&rest[..start]);
+        let matched = &rest[start..end];
+        match method {
+            VocabularyFilterMethod::Mask => out.push_str("***"),
+            VocabularyFilterMethod::Remove => {}
+            VocabularyFilterMethod::Tag => out.push_str(&format!("[{matched}]")),
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Find the first case-insensitive match of `term` in `text`, comparing
+/// char-by-char via `char::to_lowercase()` rather than pre-lowercasing the
+/// whole string and searching that: lowercasing can change a string's
+/// byte length (Turkish `İ`, German `ẞ`, various ligatures), so offsets
+/// found in a lowercased copy don't reliably land on `text`'s own char
+/// boundaries. Returns the byte range of the match in `text`.
+fn find_case_insensitive(text: &str, term: &str) -> Option<(usize, usize)> {
+    let term_chars: Vec<char> = term.chars().collect();
+    let text_chars: Vec<(usize, char)> = text.char_indices().collect();
+
+    for start in 0..text_chars.len() {
+        if start + term_chars.len() > text_chars.len() {
+            break;
+        }
+        let is_match = text_chars[start..start + term_chars.len()]
+            .iter()
+            .zip(&term_chars)
+            .all(|(&(_, tc), &pc)| tc.to_lowercase().eq(pc.to_lowercase()));
+        if is_match {
+            let match_start = text_chars[start].0;
+            let match_end = text_chars
+                .get(start + term_chars.len())
+                .map(|&(i, _)| i)
+                .unwrap_or(text.len());
+            return Some((match_start, match_end));
+        }
+    }
+    None
+}
+
 fn truncate_preview(s: &str, max: usize) -> String {
     if s.len() <= max {
         s.to_string()
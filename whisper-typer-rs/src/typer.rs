@@ -1,8 +1,13 @@
 //! Text typing using clipboard paste.
 //!
 //! Sets clipboard with arboard, then simulates Ctrl+Shift+V with enigo.
-//! Falls back to xdotool + xclip if enigo fails.
+//! Falls back to xdotool + xclip if enigo fails. A `remote` backend skips
+//! local keystroke simulation entirely and streams the text to a listening
+//! agent on another host instead.
 
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::net::UdpSocket;
 use std::process::Command;
 use std::thread;
 use std::time::Duration;
@@ -12,21 +17,26 @@ use crate::config::TyperConfig;
 
 pub struct TextTyper {
     backend: TypingBackend,
+    /// Listening agent address, used only by `TypingBackend::Remote`.
+    remote_target: String,
+    /// HMAC-SHA256 key for `TypingBackend::Remote`'s datagrams, so the
+    /// listening agent can reject forged or replayed paste commands.
+    remote_shared_secret: String,
 }
 
 enum TypingBackend {
     Enigo,
     Xdotool,
+    Remote,
 }
 
 impl TextTyper {
     pub fn new(config: &TyperConfig) -> Self {
-        let backend = if config.backend == "xdotool" {
-            // User explicitly wants xdotool
-            TypingBackend::Xdotool
-        } else {
+        let backend = match config.backend.as_str() {
+            "xdotool" => TypingBackend::Xdotool,
+            "remote" => TypingBackend::Remote,
             // Default: try enigo (arboard + enigo), fallback to xdotool
-            TypingBackend::Enigo
+            _ => TypingBackend::Enigo,
         };
 
         info!(
@@ -34,13 +44,19 @@ impl TextTyper {
             match &backend {
                 TypingBackend::Enigo => "enigo",
                 TypingBackend::Xdotool => "xdotool",
+                TypingBackend::Remote => "remote",
             }
         );
 
-        Self { backend }
+        Self {
+            backend,
+            remote_target: config.remote_target.clone(),
+            remote_shared_secret: config.remote_shared_secret.clone(),
+        }
     }
 
-    /// Type text into the currently focused window via clipboard paste.
+    /// Type text into the currently focused window via clipboard paste, or
+    /// stream it to the configured remote agent.
     pub fn type_text(&self, text: &str) {
         if text.is_empty() {
             warn!("Empty text, nothing to type");
@@ -63,9 +79,139 @@ impl TextTyper {
                     warn!("xdotool failed: {e}");
                 }
             }
+            TypingBackend::Remote => {
+                if let Err(e) = self.type_with_remote(text) {
+                    warn!("Remote typing failed: {e}");
+                }
+            }
+        }
+    }
+
+    /// Stream `text` to the listening agent at `remote_target`. The agent is
+    /// expected to apply the same clipboard-set + paste semantics locally
+    /// (see `type_with_enigo`/`type_with_xdotool`) on receipt, after
+    /// verifying each datagram's `hmac` field against the same shared
+    /// secret (see `sign`) — plain UDP has no sender authentication, so an
+    /// unsigned datagram is indistinguishable from one forged by anything
+    /// else on the network.
+    ///
+    /// `send_to` succeeding only means the local kernel accepted the
+    /// packet for delivery, not that the agent received or applied it —
+    /// there is no ack, so this can't report actual delivery.
+    fn type_with_remote(&self, text: &str) -> Result<(), String> {
+        if self.remote_target.is_empty() {
+            return Err("typer.remote_target is not configured".to_string());
+        }
+        if self.remote_shared_secret.is_empty() {
+            return Err("typer.remote_shared_secret is not configured".to_string());
+        }
+
+        let socket =
+            UdpSocket::bind("0.0.0.0:0").map_err(|e| format!("Failed to bind UDP socket: {e}"))?;
+
+        // Lightweight handshake: a signed "hello" datagram so the agent can
+        // authenticate us before the (possibly larger) paste payload
+        // arrives. No reply to it is read.
+        self.send_signed(&socket, serde_json::json!({ "type": "hello" }))?;
+
+        let bytes_sent = self.send_signed(&socket, serde_json::json!({ "type": "paste", "text": text }))?;
+
+        debug!(
+            "Handed {bytes_sent} bytes to the kernel for {} (UDP is best-effort — this does not confirm the agent received or applied it)",
+            self.remote_target
+        );
+        Ok(())
+    }
+
+    /// Serialize `payload`, append an `hmac` field signing it with
+    /// `remote_shared_secret`, and send the result to `remote_target`.
+    /// Returns the number of bytes sent.
+    fn send_signed(&self, socket: &UdpSocket, mut payload: serde_json::Value) -> Result<usize, String> {
+        let unsigned = serde_json::to_vec(&payload)
+            .map_err(|e| format!("Failed to serialize payload: {e}"))?;
+        let tag = sign(&self.remote_shared_secret, &unsigned);
+        payload["hmac"] = serde_json::Value::String(tag);
+
+        let bytes = serde_json::to_vec(&payload)
+            .map_err(|e| format!("Failed to serialize signed payload: {e}"))?;
+        socket
+            .send_to(&bytes, &self.remote_target)
+            .map_err(|e| format!("Failed to send to {}: {e}", self.remote_target))?;
+        Ok(bytes.len())
+    }
+
+    /// Press a single named key (e.g. "Return", "Escape", "BackSpace"), for
+    /// voice commands that act instead of typing text. See
+    /// `crate::commands::CommandAction::KeyPress`.
+    pub fn press_key(&self, key: &str) {
+        self.press_chord(&[], key);
+    }
+
+    /// Press a modifier chord (e.g. modifiers `["Control"]`, key `"a"` for
+    /// select-all). See `crate::commands::CommandAction::KeyChord`.
+    pub fn press_chord(&self, modifiers: &[String], key: &str) {
+        match &self.backend {
+            TypingBackend::Enigo => {
+                if let Err(e) = self.press_with_enigo(modifiers, key) {
+                    warn!("Enigo key press failed: {e}, falling back to xdotool");
+                    if let Err(e2) = self.press_with_xdotool(modifiers, key) {
+                        warn!("xdotool fallback also failed: {e2}");
+                    }
+                }
+            }
+            TypingBackend::Xdotool => {
+                if let Err(e) = self.press_with_xdotool(modifiers, key) {
+                    warn!("xdotool key press failed: {e}");
+                }
+            }
+            TypingBackend::Remote => {
+                warn!("Key press is not supported by the remote typer backend");
+            }
         }
     }
 
+    fn press_with_enigo(&self, modifiers: &[String], key: &str) -> Result<(), String> {
+        use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+
+        let mod_keys = modifiers
+            .iter()
+            .map(|m| enigo_key(m))
+            .collect::<Result<Vec<Key>, String>>()?;
+        let main_key = enigo_key(key)?;
+
+        let mut enigo =
+            Enigo::new(&Settings::default()).map_err(|e| format!("Failed to init enigo: {e}"))?;
+
+        for k in &mod_keys {
+            enigo.key(*k, Direction::Press).map_err(|e| format!("Key press failed: {e}"))?;
+        }
+        enigo.key(main_key, Direction::Click).map_err(|e| format!("Key click failed: {e}"))?;
+        for k in mod_keys.iter().rev() {
+            enigo.key(*k, Direction::Release).map_err(|e| format!("Key release failed: {e}"))?;
+        }
+
+        debug!("Pressed key via enigo: {modifiers:?}+{key}");
+        Ok(())
+    }
+
+    fn press_with_xdotool(&self, modifiers: &[String], key: &str) -> Result<(), String> {
+        let mut parts: Vec<String> = modifiers.iter().map(|m| xdotool_key_name(m)).collect();
+        parts.push(xdotool_key_name(key));
+        let combo = parts.join("+");
+
+        let status = Command::new("xdotool")
+            .args(["key", "--clearmodifiers", &combo])
+            .status()
+            .map_err(|e| format!("xdotool failed: {e}"))?;
+
+        if !status.success() {
+            return Err("xdotool exited with non-zero status".to_string());
+        }
+
+        debug!("Pressed key via xdotool: {combo}");
+        Ok(())
+    }
+
     fn type_with_enigo(&self, text: &str) -> Result<(), String> {
         use arboard::Clipboard;
         use enigo::{Direction, Enigo, Key, Keyboard, Settings};
@@ -139,3 +285,68 @@ impl TextTyper {
         Ok(())
     }
 }
+
+/// HMAC-SHA256 `message` with `secret`, hex-encoded, for authenticating
+/// `TypingBackend::Remote` datagrams (see `TextTyper::send_signed`).
+fn sign(secret: &str, message: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts a key of any length");
+    mac.update(message);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Map a command key name (case-insensitive; "ctrl"/"control" etc. both
+/// accepted) to an enigo `Key`. Single characters fall through to
+/// `Key::Unicode`.
+fn enigo_key(name: &str) -> Result<enigo::Key, String> {
+    use enigo::Key;
+    Ok(match name.to_lowercase().as_str() {
+        "control" | "ctrl" => Key::Control,
+        "shift" => Key::Shift,
+        "alt" => Key::Alt,
+        "meta" | "super" | "cmd" => Key::Meta,
+        "escape" | "esc" => Key::Escape,
+        "return" | "enter" => Key::Return,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "space" => Key::Space,
+        "up" => Key::UpArrow,
+        "down" => Key::DownArrow,
+        "left" => Key::LeftArrow,
+        "right" => Key::RightArrow,
+        "home" => Key::Home,
+        "end" => Key::End,
+        other if other.chars().count() == 1 => Key::Unicode(other.chars().next().unwrap()),
+        other => return Err(format!("Unknown key: {other}")),
+    })
+}
+
+/// Map a command key name to the xdotool key name it expects (mostly
+/// title-cased special keys; xdotool accepts lowercase modifier names and
+/// single characters as-is).
+fn xdotool_key_name(name: &str) -> String {
+    match name.to_lowercase().as_str() {
+        "control" | "ctrl" => "ctrl".to_string(),
+        "shift" => "shift".to_string(),
+        "alt" => "alt".to_string(),
+        "meta" | "super" | "cmd" => "super".to_string(),
+        "escape" | "esc" => "Escape".to_string(),
+        "return" | "enter" => "Return".to_string(),
+        "tab" => "Tab".to_string(),
+        "backspace" => "BackSpace".to_string(),
+        "delete" | "del" => "Delete".to_string(),
+        "space" => "space".to_string(),
+        "up" => "Up".to_string(),
+        "down" => "Down".to_string(),
+        "left" => "Left".to_string(),
+        "right" => "Right".to_string(),
+        "home" => "Home".to_string(),
+        "end" => "End".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,231 @@
+//! Auto-stop frame classifiers, selected by `SilenceConfig::mode`.
+//!
+//! `AudioRecorder` feeds resampled (target-rate) samples to whichever
+//! `SilenceDetector` variant is active and debounces its per-frame
+//! speech/non-speech verdicts into a hangover-based auto-stop the same way
+//! regardless of which engine produced them (see `recorder.rs`).
+//!
+//! `Energy` wraps the original WebRTC VAD amplitude gate. `Spectral`
+//! processes overlapping Hann-windowed frames through a real FFT and
+//! classifies a frame as speech when its total energy clears an adaptive
+//! threshold derived from a rolling noise-floor estimate and the fraction
+//! of its energy inside `speech_band_hz` clears a fixed ratio
+//! (`RATIO_THRESHOLD`; this ratio gate does not adapt) — steadier against hum,
+//! fans, and keyboard clatter than a single broadband amplitude gate.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use realfft::{num_complex::Complex32, RealFftPlanner, RealToComplex};
+use webrtc_vad::{SampleRate as VadSampleRate, Vad, VadMode};
+
+use crate::config::{SilenceConfig, SilenceMode};
+
+/// Dispatches to whichever engine `SilenceConfig::mode` selects.
+pub enum SilenceDetector {
+    Energy(EnergyDetector),
+    Spectral(SpectralDetector),
+}
+
+impl SilenceDetector {
+    pub fn new(sample_rate: u32, config: &SilenceConfig) -> Self {
+        match config.mode {
+            SilenceMode::Energy => SilenceDetector::Energy(EnergyDetector::new(sample_rate, config)),
+            SilenceMode::Spectral => SilenceDetector::Spectral(SpectralDetector::new(sample_rate, config)),
+        }
+    }
+
+    /// Samples the caller must accumulate before calling `is_voice`.
+    pub fn frame_len(&self) -> usize {
+        match self {
+            SilenceDetector::Energy(d) => d.frame_len,
+            SilenceDetector::Spectral(d) => d.hop_len,
+        }
+    }
+
+    /// Classify one frame of exactly `frame_len()` samples as voiced.
+    pub fn is_voice(&mut self, frame: &[f32]) -> bool {
+        match self {
+            SilenceDetector::Energy(d) => d.is_voice(frame),
+            SilenceDetector::Spectral(d) => d.is_voice(frame),
+        }
+    }
+}
+
+/// The original WebRTC-VAD amplitude gate, operating on 16-bit PCM frames.
+pub struct EnergyDetector {
+    vad: Vad,
+    frame_len: usize,
+}
+
+impl EnergyDetector {
+    fn new(sample_rate: u32, config: &SilenceConfig) -> Self {
+        Self {
+            vad: Vad::new_with_rate_and_mode(vad_sample_rate(sample_rate), vad_mode(config.vad_aggressiveness)),
+            frame_len: (sample_rate as usize * config.vad_frame_ms as usize) / 1000,
+        }
+    }
+
+    fn is_voice(&mut self, frame: &[f32]) -> bool {
+        let pcm: Vec<i16> = frame
+            .iter()
+            .map(|s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .collect();
+        self.vad.is_voice_segment(&pcm).unwrap_or(false)
+    }
+}
+
+/// Map `SilenceConfig::vad_aggressiveness` (0-3) to the WebRTC VAD mode;
+/// out-of-range values clamp to the most aggressive setting.
+fn vad_mode(aggressiveness: u8) -> VadMode {
+    match aggressiveness {
+        0 => VadMode::Quality,
+        1 => VadMode::LowBitrate,
+        2 => VadMode::Aggressive,
+        _ => VadMode::VeryAggressive,
+    }
+}
+
+/// WebRTC VAD only accepts 8/16/32/48kHz; fall back to 16kHz (Whisper's
+/// own input rate, and what `config.audio.sample_rate` defaults to) for
+/// anything else.
+fn vad_sample_rate(sample_rate: u32) -> VadSampleRate {
+    match sample_rate {
+        8000 => VadSampleRate::Rate8kHz,
+        32000 => VadSampleRate::Rate32kHz,
+        48000 => VadSampleRate::Rate48kHz,
+        _ => VadSampleRate::Rate16kHz,
+    }
+}
+
+/// Frame (window) size for the spectral detector's FFT, in milliseconds.
+const WINDOW_MS: u32 = 25;
+/// Hop size between successive frames, in milliseconds — frames overlap
+/// by `WINDOW_MS - HOP_MS` so a transition into/out of speech isn't
+/// missed between windows.
+const HOP_MS: u32 = 10;
+/// Width of the rolling noise-floor window, in milliseconds.
+const NOISE_FLOOR_WINDOW_MS: u32 = 1000;
+/// Factor by which a frame's total energy must exceed the rolling noise
+/// floor to count as speech.
+const ENERGY_MARGIN: f32 = 2.5;
+/// Fixed minimum fraction of a frame's energy that must fall inside
+/// `speech_band_hz` for it to count as speech.
+const RATIO_THRESHOLD: f32 = 0.45;
+
+/// FFT-based in-band energy ratio detector; see module docs.
+pub struct SpectralDetector {
+    hop_len: usize,
+    window_len: usize,
+    hann: Vec<f32>,
+    /// Trailing `window_len - hop_len` samples from the previous frame,
+    /// so each new hop of samples completes an overlapping window.
+    carry: Vec<f32>,
+    fft: Arc<dyn RealToComplex<f32>>,
+    scratch: Vec<f32>,
+    spectrum: Vec<Complex32>,
+    /// Inclusive FFT bin range covering `speech_band_hz`.
+    band_bins: (usize, usize),
+    /// Rolling total-energy history over the last ~`NOISE_FLOOR_WINDOW_MS`,
+    /// whose minimum estimates the steady-state noise floor.
+    recent_energies: VecDeque<f32>,
+    max_recent: usize,
+    noise_floor: f32,
+}
+
+impl SpectralDetector {
+    fn new(sample_rate: u32, config: &SilenceConfig) -> Self {
+        let window_len = (sample_rate as usize * WINDOW_MS as usize) / 1000;
+        let hop_len = (sample_rate as usize * HOP_MS as usize) / 1000;
+        let hann = hann_window(window_len);
+
+        let fft = RealFftPlanner::<f32>::new().plan_fft_forward(window_len);
+        let scratch = vec![0.0; window_len];
+        let spectrum = fft.make_output_vec();
+
+        let bin_hz = sample_rate as f32 / window_len as f32;
+        let (lo_hz, hi_hz) = config.speech_band_hz;
+        let band_bins = (
+            (lo_hz / bin_hz).floor().max(0.0) as usize,
+            ((hi_hz / bin_hz).ceil() as usize).min(spectrum.len().saturating_sub(1)),
+        );
+
+        let max_recent = (NOISE_FLOOR_WINDOW_MS / HOP_MS).max(1) as usize;
+
+        Self {
+            hop_len,
+            window_len,
+            hann,
+            carry: vec![0.0; window_len - hop_len],
+            fft,
+            scratch,
+            spectrum,
+            band_bins,
+            recent_energies: VecDeque::with_capacity(max_recent),
+            max_recent,
+            noise_floor: 0.0,
+        }
+    }
+
+    fn is_voice(&mut self, hop: &[f32]) -> bool {
+        self.scratch[..self.carry.len()].copy_from_slice(&self.carry);
+        self.scratch[self.carry.len()..].copy_from_slice(hop);
+
+        let carry_start = self.scratch.len() - self.carry.len();
+        self.carry.copy_from_slice(&self.scratch[carry_start..]);
+
+        for (sample, coeff) in self.scratch.iter_mut().zip(self.hann.iter()) {
+            *sample *= coeff;
+        }
+
+        // `process` consumes `self.scratch`, so windowing above must run
+        // before this call on each frame.
+        if self.fft.process(&mut self.scratch, &mut self.spectrum).is_err() {
+            return false;
+        }
+
+        let total_energy: f32 = self.spectrum.iter().map(|c| c.norm_sqr()).sum();
+        let (lo, hi) = self.band_bins;
+        let band_energy: f32 = self.spectrum[lo..=hi].iter().map(|c| c.norm_sqr()).sum();
+        let ratio = if total_energy > f32::EPSILON { band_energy / total_energy } else { 0.0 };
+
+        self.update_noise_floor(total_energy);
+
+        let energy_gate = self.noise_floor * ENERGY_MARGIN;
+        total_energy > energy_gate.max(f32::EPSILON) && ratio > RATIO_THRESHOLD
+    }
+
+    /// Track the minimum total frame energy over the last ~1s, then EMA
+    /// the noise floor toward that minimum so a sudden loud frame doesn't
+    /// drag it up instantly and a sudden quiet one doesn't drop it out
+    /// from under ongoing speech.
+    fn update_noise_floor(&mut self, total_energy: f32) {
+        self.recent_energies.push_back(total_energy);
+        while self.recent_energies.len() > self.max_recent {
+            self.recent_energies.pop_front();
+        }
+        let window_min = self
+            .recent_energies
+            .iter()
+            .copied()
+            .fold(f32::INFINITY, f32::min);
+
+        const FLOOR_EMA_RATE: f32 = 0.1;
+        self.noise_floor = if self.noise_floor == 0.0 {
+            window_min
+        } else {
+            self.noise_floor + FLOOR_EMA_RATE * (window_min - self.noise_floor)
+        };
+    }
+}
+
+/// Symmetric Hann window of length `len`, used to taper each FFT frame's
+/// edges and limit spectral leakage.
+fn hann_window(len: usize) -> Vec<f32> {
+    if len <= 1 {
+        return vec![1.0; len];
+    }
+    (0..len)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (len - 1) as f32).cos())
+        .collect()
+}